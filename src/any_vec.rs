@@ -0,0 +1,173 @@
+use std::alloc::Layout;
+use std::any::{Any, TypeId};
+
+use crate::MyVec;
+
+/// The operations an [`AnyVec`] can perform without knowing its element
+/// type.
+trait ErasedVec: Any + Send + Sync {
+    fn len(&self) -> usize;
+
+    fn truncate(&mut self, len: usize);
+
+    fn swap_remove(&mut self, idx: usize);
+
+    fn as_any(&self) -> &dyn Any;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+}
+
+impl<T: Send + Sync + 'static> ErasedVec for MyVec<T> {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn truncate(&mut self, len: usize) {
+        MyVec::truncate(self, len);
+    }
+
+    fn swap_remove(&mut self, idx: usize) {
+        MyVec::swap_remove(self, idx);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+/// A vector of a single element type chosen at runtime.
+///
+/// The element type is fixed at construction; pushes and borrows name it
+/// again and are checked against it, while length, truncation and removal
+/// work without knowing it, as a storage table keyed by `TypeId` needs.
+pub struct AnyVec {
+    inner: Box<dyn ErasedVec>,
+    type_id: TypeId,
+    layout: Layout,
+}
+
+impl AnyVec {
+    pub fn new<T: Send + Sync + 'static>() -> Self {
+        Self::from_myvec(MyVec::<T>::new())
+    }
+
+    pub fn from_myvec<T: Send + Sync + 'static>(vec: MyVec<T>) -> Self {
+        Self {
+            inner: Box::new(vec),
+            type_id: TypeId::of::<T>(),
+            layout: Layout::new::<T>(),
+        }
+    }
+
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    pub fn element_layout(&self) -> Layout {
+        self.layout
+    }
+
+    pub fn is<T: 'static>(&self) -> bool {
+        self.type_id == TypeId::of::<T>()
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&mut self) {
+        self.inner.truncate(0);
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        self.inner.truncate(len);
+    }
+
+    /// Drops the element at `idx`, moving the last element into its place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn swap_remove(&mut self, idx: usize) {
+        assert!(idx < self.len(), "index out of bounds");
+        self.inner.swap_remove(idx);
+    }
+
+    /// Pushes `value`, or hands it back if `T` is not the element type.
+    pub fn push<T: 'static>(&mut self, value: T) -> Result<(), T> {
+        match self.downcast_mut::<T>() {
+            Some(vec) => {
+                vec.push(value);
+                Ok(())
+            }
+            None => Err(value),
+        }
+    }
+
+    pub fn downcast_ref<T: 'static>(&self) -> Option<&MyVec<T>> {
+        self.inner.as_any().downcast_ref()
+    }
+
+    pub fn downcast_mut<T: 'static>(&mut self) -> Option<&mut MyVec<T>> {
+        self.inner.as_any_mut().downcast_mut()
+    }
+
+    pub fn as_slice<T: 'static>(&self) -> Option<&[T]> {
+        self.downcast_ref().map(|vec| &vec[..])
+    }
+
+    pub fn as_mut_slice<T: 'static>(&mut self) -> Option<&mut [T]> {
+        self.downcast_mut().map(|vec| &mut vec[..])
+    }
+
+    /// Recovers the typed vector, or returns `self` unchanged if `T` is not
+    /// the element type.
+    pub fn into_myvec<T: 'static>(self) -> Result<MyVec<T>, Self> {
+        if !self.is::<T>() {
+            return Err(self);
+        }
+
+        Ok(*self.inner.into_any().downcast().unwrap())
+    }
+}
+
+#[test]
+fn any_vec_checks_type() {
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    let mut v = AnyVec::new::<Arc<u32>>();
+    let shared = Arc::new(7u32);
+    for _ in 0..4 {
+        v.push(Arc::clone(&shared)).unwrap();
+    }
+    assert_eq!(Err(1u32), v.push(1u32));
+    assert!(v.as_slice::<u32>().is_none());
+    assert_eq!(Layout::new::<Arc<u32>>(), v.element_layout());
+
+    // removal drops through the erased element type
+    v.swap_remove(1);
+    v.truncate(2);
+    assert_eq!(2, v.len());
+    assert_eq!(3, Arc::strong_count(&shared));
+
+    let v = v.into_myvec::<Rc<u32>>().err().unwrap();
+    let typed = v.into_myvec::<Arc<u32>>().ok().unwrap();
+    assert_eq!(7, *typed[1]);
+    drop(typed);
+    assert_eq!(1, Arc::strong_count(&shared));
+}
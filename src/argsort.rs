@@ -0,0 +1,53 @@
+use std::cmp::Ordering;
+
+use crate::MyVec;
+
+impl<T> MyVec<T> {
+    /// Returns the indices that would sort the vector, leaving the elements
+    /// where they are: `self[idx[0]] <= self[idx[1]] <= ...`.
+    ///
+    /// The sort is stable, so equal elements keep their relative order. Pass
+    /// the result to [`MyVec::apply_permutation`] to sort this vector and
+    /// any parallel ones the same way.
+    pub fn argsort(&self) -> MyVec<usize>
+    where
+        T: Ord,
+    {
+        self.argsort_by(T::cmp)
+    }
+
+    pub fn argsort_by_key<K: Ord>(&self, mut f: impl FnMut(&T) -> K) -> MyVec<usize> {
+        self.argsort_by(|a, b| f(a).cmp(&f(b)))
+    }
+
+    pub fn argsort_by(&self, mut cmp: impl FnMut(&T, &T) -> Ordering) -> MyVec<usize> {
+        let mut indices = MyVec::with_capacity(self.len);
+        for i in 0..self.len {
+            indices.push(i);
+        }
+
+        indices.sort_by(|&a, &b| cmp(&self[a], &self[b]));
+        indices
+    }
+}
+
+#[test]
+fn argsort_sorts_parallel_vecs() {
+    let mut scores = MyVec::new();
+    let mut players = MyVec::new();
+    for (player, score) in [("x", 30), ("y", 10), ("z", 30), ("w", 20)] {
+        players.push(player);
+        scores.push(score);
+    }
+
+    let order = scores.argsort();
+    assert_eq!([1, 3, 0, 2], *order);
+
+    scores.apply_permutation(&order);
+    players.apply_permutation(&order);
+    assert_eq!([10, 20, 30, 30], *scores);
+    assert_eq!(["y", "w", "x", "z"], *players);
+
+    let by_name = players.argsort_by_key(|p| *p);
+    assert_eq!(["w", "x", "y", "z"], *players.gather(&by_name));
+}
@@ -0,0 +1,56 @@
+use crate::MyVec;
+
+/// A growable set of bits, LSB first within each word.
+pub(crate) struct Bitset {
+    words: MyVec<u64>,
+}
+
+impl Bitset {
+    pub(crate) fn new(bits: usize) -> Self {
+        Self {
+            words: MyVec::zeroed(bits.div_ceil(64)),
+        }
+    }
+
+    pub(crate) fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    pub(crate) fn get(&self, bit: usize) -> bool {
+        self.words[bit / 64] & (1 << (bit % 64)) != 0
+    }
+
+    pub(crate) fn set(&mut self, bit: usize) {
+        self.words[bit / 64] |= 1 << (bit % 64);
+    }
+
+    pub(crate) fn unset(&mut self, bit: usize) {
+        self.words[bit / 64] &= !(1 << (bit % 64));
+    }
+
+    /// Makes room for at least `bits` bits, all new ones unset.
+    pub(crate) fn grow_to(&mut self, bits: usize) {
+        let words = bits.div_ceil(64);
+        if words > self.words.len() {
+            self.words.resize_zeroed(words);
+        }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// Sets every bit set in `other`; both must be the same size.
+    pub(crate) fn union_with(&mut self, other: &Bitset) {
+        for (a, b) in self.words.iter_mut().zip(other.words()) {
+            *a |= b;
+        }
+    }
+
+    /// Unsets every bit unset in `other`; both must be the same size.
+    pub(crate) fn intersect_with(&mut self, other: &Bitset) {
+        for (a, b) in self.words.iter_mut().zip(other.words()) {
+            *a &= b;
+        }
+    }
+}
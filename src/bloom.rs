@@ -0,0 +1,143 @@
+use std::collections::hash_map::DefaultHasher;
+use std::f64::consts::LN_2;
+use std::hash::{Hash, Hasher};
+
+use crate::bitset::Bitset;
+
+/// A Bloom filter: a set that can answer "definitely not present" or
+/// "probably present" in a fixed number of bits.
+///
+/// Hashing uses `DefaultHasher` with its fixed keys, so filters built with
+/// the same parameters agree on every bit and can be combined.
+pub struct BloomFilter {
+    bits: Bitset,
+    len: usize,
+    hashes: u32,
+}
+
+impl BloomFilter {
+    /// Sizes a filter to hold `expected_items` with roughly the given false
+    /// positive rate.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `0 < false_positive_rate < 1`.
+    pub fn new(expected_items: usize, false_positive_rate: f64) -> Self {
+        assert!(
+            false_positive_rate > 0.0 && false_positive_rate < 1.0,
+            "false positive rate must be between 0 and 1"
+        );
+
+        let items = expected_items.max(1) as f64;
+        let bits = (-items * false_positive_rate.ln() / (LN_2 * LN_2)).ceil();
+        let hashes = (bits / items * LN_2).round().max(1.0);
+
+        Self::with_params(bits as usize, hashes as u32)
+    }
+
+    /// Creates a filter with exactly `bits` bits, rounded up to a whole
+    /// number of words, probed `hashes` times per item.
+    pub fn with_params(bits: usize, hashes: u32) -> Self {
+        assert!(bits > 0 && hashes > 0, "filter parameters must be non-zero");
+
+        let len = bits.div_ceil(64) * 64;
+
+        Self {
+            bits: Bitset::new(len),
+            len,
+            hashes,
+        }
+    }
+
+    pub fn bits(&self) -> usize {
+        self.len
+    }
+
+    pub fn hashes(&self) -> u32 {
+        self.hashes
+    }
+
+    pub fn insert<T: Hash + ?Sized>(&mut self, item: &T) {
+        for bit in self.probes(item) {
+            self.bits.set(bit);
+        }
+    }
+
+    /// Returns `false` if `item` was never inserted, and `true` if it
+    /// probably was.
+    pub fn contains<T: Hash + ?Sized>(&self, item: &T) -> bool {
+        self.probes(item).all(|bit| self.bits.get(bit))
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    /// Adds every item of `other`, as if it had been inserted here.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the filters were built with different parameters.
+    pub fn union(&mut self, other: &BloomFilter) {
+        self.assert_compatible(other);
+        self.bits.union_with(&other.bits);
+    }
+
+    /// Keeps only what both filters may contain. The result can have more
+    /// false positives than a filter built from the intersection directly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the filters were built with different parameters.
+    pub fn intersect(&mut self, other: &BloomFilter) {
+        self.assert_compatible(other);
+        self.bits.intersect_with(&other.bits);
+    }
+
+    fn assert_compatible(&self, other: &BloomFilter) {
+        assert!(
+            self.len == other.len && self.hashes == other.hashes,
+            "filters must have the same size and hash count"
+        );
+    }
+
+    /// Double hashing: probe `i` is `h1 + i * h2`, from two halves of one
+    /// 64-bit hash.
+    fn probes<T: Hash + ?Sized>(&self, item: &T) -> impl Iterator<Item = usize> {
+        let mut hasher = DefaultHasher::new();
+        item.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let (h1, h2) = (hash as u32 as u64, (hash >> 32) | 1);
+        let len = self.len as u64;
+
+        (0..self.hashes as u64).map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) % len) as usize)
+    }
+}
+
+#[test]
+fn bloom_filter_membership() {
+    let mut evens = BloomFilter::new(1000, 0.01);
+    assert_eq!(7, evens.hashes());
+    let mut small = BloomFilter::new(1000, 0.01);
+
+    for i in (0..2000).step_by(2) {
+        evens.insert(&i);
+    }
+    for i in 0..10 {
+        small.insert(&i);
+    }
+
+    assert!((0..2000).step_by(2).all(|i| evens.contains(&i)));
+    let false_positives = (1..2000).step_by(2).filter(|i| evens.contains(i)).count();
+    assert!(false_positives < 30, "{false_positives} false positives");
+
+    evens.intersect(&small);
+    assert!([0, 2, 4, 6, 8].iter().all(|i| evens.contains(i)));
+    assert!(!evens.contains(&1000));
+
+    evens.union(&small);
+    assert!((0..10).all(|i| evens.contains(&i)));
+    evens.clear();
+    assert!(!evens.contains("anything"));
+}
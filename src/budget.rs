@@ -0,0 +1,144 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use crate::{MyVec, TryReserveError};
+
+/// A byte limit shared by any number of vectors.
+///
+/// Every attached vector debits the budget for the capacity it allocates and
+/// credits it back when its buffer is freed. Growth that would overdraw the
+/// budget fails with [`TryReserveError::BudgetExceeded`] from the `try_*`
+/// APIs, and panics from the infallible ones.
+///
+/// Cloning the handle shares the same budget.
+#[derive(Clone)]
+pub struct MemoryBudget {
+    inner: Arc<BudgetInner>,
+}
+
+struct BudgetInner {
+    limit: usize,
+    used: AtomicUsize,
+}
+
+impl MemoryBudget {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            inner: Arc::new(BudgetInner {
+                limit,
+                used: AtomicUsize::new(0),
+            }),
+        }
+    }
+
+    pub fn limit(&self) -> usize {
+        self.inner.limit
+    }
+
+    /// Returns the bytes currently held by attached vectors.
+    pub fn used(&self) -> usize {
+        self.inner.used.load(Ordering::Relaxed)
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.limit() - self.used()
+    }
+
+    pub(crate) fn try_debit(&self, bytes: usize) -> Result<(), TryReserveError> {
+        self.inner
+            .used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                used.checked_add(bytes)
+                    .filter(|&total| total <= self.inner.limit)
+            })
+            .map(|_| ())
+            .map_err(|_| TryReserveError::BudgetExceeded)
+    }
+
+    pub(crate) fn credit(&self, bytes: usize) {
+        self.inner.used.fetch_sub(bytes, Ordering::Relaxed);
+    }
+}
+
+impl<T> MyVec<T> {
+    /// Creates an empty vector whose allocations count against `budget`.
+    pub fn with_budget(budget: &MemoryBudget) -> Self {
+        let mut v = Self::new();
+        v.buf.budget = Some(budget.clone());
+        v
+    }
+
+    /// Moves the vector onto `budget`, debiting its current capacity and
+    /// crediting any budget it was attached to before. Fails, leaving the
+    /// vector as it was, if the capacity does not fit in `budget`.
+    pub fn attach_budget(&mut self, budget: &MemoryBudget) -> Result<(), TryReserveError> {
+        let bytes = self.allocated_bytes();
+        budget.try_debit(bytes)?;
+
+        if let Some(old) = self.buf.budget.replace(budget.clone()) {
+            old.credit(bytes);
+        }
+
+        Ok(())
+    }
+
+    /// Detaches the vector from its budget, crediting its capacity back.
+    pub fn detach_budget(&mut self) {
+        if let Some(old) = self.buf.budget.take() {
+            old.credit(self.allocated_bytes());
+        }
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.buf.current_layout().map_or(0, |layout| layout.size())
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn budget_shared_between_vectors() {
+    let budget = MemoryBudget::new(64);
+
+    let mut a = MyVec::<u64>::with_budget(&budget);
+    a.try_reserve(4).unwrap();
+    assert_eq!(32, budget.used());
+
+    let mut b = MyVec::<u64>::with_budget(&budget);
+    b.try_reserve(4).unwrap();
+    assert_eq!(0, budget.remaining());
+    assert_eq!(Err(TryReserveError::BudgetExceeded), b.try_reserve(5));
+    assert_eq!(4, b.capacity());
+
+    drop(a);
+    assert_eq!(32, budget.used());
+    b.try_reserve(8).unwrap();
+    assert_eq!(64, budget.used());
+
+    b.detach_budget();
+    assert_eq!(0, budget.used());
+
+    let mut c = MyVec::<u8>::with_capacity(100);
+    assert_eq!(
+        Err(TryReserveError::BudgetExceeded),
+        c.attach_budget(&budget)
+    );
+    let small = MemoryBudget::new(1000);
+    c.attach_budget(&small).unwrap();
+    assert_eq!(100, small.used());
+    drop(c);
+    assert_eq!(0, small.used());
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn resize_zeroed_debits_budget() {
+    let budget = MemoryBudget::new(64);
+
+    let mut v = MyVec::<u32>::with_budget(&budget);
+    v.resize_zeroed(8);
+    assert_eq!(32, budget.used());
+    assert_eq!(Err(TryReserveError::BudgetExceeded), v.try_reserve(16));
+
+    drop(v);
+    assert_eq!(0, budget.used());
+}
@@ -0,0 +1,112 @@
+use std::ptr;
+
+use crate::MyVec;
+
+/// How much [`MyVec::chunk_mut`] reserves once the spare capacity runs out.
+#[cfg(not(no_global_oom_handling))]
+const MIN_CHUNK: usize = 64;
+
+impl MyVec<u8> {
+    /// Removes the first `at` bytes and returns them as their own buffer,
+    /// like `BytesMut::split_to`.
+    ///
+    /// Unlike `BytesMut`, the two halves can't share an allocation, so this
+    /// copies the front out and shifts the rest down, costing O(`len`)
+    /// rather than O(`at`). Splitting many small frames off a large buffer
+    /// one at a time is therefore quadratic; decode them from the slice and
+    /// [`advance`](MyVec::advance) past all of them at once instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    #[cfg(not(no_global_oom_handling))]
+    pub fn split_to(&mut self, at: usize) -> MyVec<u8> {
+        assert!(at <= self.len, "split index out of bounds");
+
+        let mut front = MyVec::with_capacity(at);
+        front.extend_from_slice(&self[..at]);
+        self.advance(at);
+
+        front
+    }
+
+    /// Discards the first `n` bytes.
+    ///
+    /// The bytes kept are shifted down to the start of the buffer, so this
+    /// costs O(`len - n`) however small `n` is.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n > len`.
+    pub fn advance(&mut self, n: usize) {
+        assert!(n <= self.len, "cannot advance past the end");
+
+        unsafe { ptr::copy(self.ptr().add(n), self.ptr(), self.len - n) };
+        self.len -= n;
+    }
+
+    /// Returns how many bytes can be written before the buffer has to
+    /// grow.
+    pub fn remaining_mut(&self) -> usize {
+        self.cap() - self.len
+    }
+
+    /// Returns the uninitialized space after the bytes, reserving more if
+    /// there is none; fill a prefix of it and commit it with
+    /// [`MyVec::advance_mut`].
+    #[cfg(not(no_global_oom_handling))]
+    pub fn chunk_mut(&mut self) -> &mut [std::mem::MaybeUninit<u8>] {
+        if self.remaining_mut() == 0 {
+            self.reserve(MIN_CHUNK);
+        }
+        // safe code can write `MaybeUninit::uninit()` into the chunk
+        self.buf.init = 0;
+
+        unsafe {
+            std::slice::from_raw_parts_mut(
+                self.ptr().add(self.len) as *mut std::mem::MaybeUninit<u8>,
+                self.remaining_mut(),
+            )
+        }
+    }
+
+    /// Marks the next `n` bytes of spare capacity as part of the buffer.
+    ///
+    /// # Safety
+    ///
+    /// Those bytes must have been initialized, e.g. through
+    /// [`MyVec::chunk_mut`], and `n` must not exceed
+    /// [`MyVec::remaining_mut`].
+    pub unsafe fn advance_mut(&mut self, n: usize) {
+        self.len += n;
+        self.record_len();
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn frames_split_off_the_front() {
+    let mut buf = MyVec::new();
+
+    // two length-prefixed frames and the start of a third
+    for byte in [3, b'a', b'b', b'c', 1, b'd', 4, b'e'] {
+        let chunk = buf.chunk_mut();
+        chunk[0].write(byte);
+        unsafe { buf.advance_mut(1) };
+    }
+    assert_eq!(MIN_CHUNK - 8, buf.remaining_mut());
+
+    let mut frames = MyVec::new();
+    while let Some(&len) = buf.first() {
+        if buf.len() <= len as usize {
+            break;
+        }
+        buf.advance(1);
+        frames.push(buf.split_to(len as usize));
+    }
+
+    assert_eq!(2, frames.len());
+    assert_eq!(b"abc", &*frames[0]);
+    assert_eq!(b"d", &*frames[1]);
+    assert_eq!([4, b'e'], *buf);
+}
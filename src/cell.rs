@@ -0,0 +1,31 @@
+use std::cell::Cell;
+
+use crate::MyVec;
+
+impl<T> MyVec<T> {
+    /// Views the elements as cells, so several of them can be written
+    /// through shared references at once.
+    ///
+    /// Takes `&mut self` because that is what proves no other references to
+    /// the elements exist; `Cell` keeps the view on the current thread.
+    pub fn as_cell_slice(&mut self) -> &[Cell<T>] {
+        Cell::from_mut(&mut self[..]).as_slice_of_cells()
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn cells_alias_elements() {
+    let mut dist = MyVec::from_elem(u32::MAX, 4);
+    dist[0] = 0;
+
+    let cells = dist.as_cell_slice();
+    for (from, to, weight) in [(0, 1, 4), (0, 2, 1), (2, 1, 2), (1, 3, 5)] {
+        let candidate = cells[from].get().saturating_add(weight);
+        if candidate < cells[to].get() {
+            cells[to].set(candidate);
+        }
+    }
+
+    assert_eq!([0, 3, 1, 8], *dist);
+}
@@ -0,0 +1,213 @@
+use std::error::Error;
+use std::fmt;
+use std::mem;
+use std::ops::Deref;
+use std::slice;
+
+use crate::{MyVec, Pod};
+
+/// When a [`ChecksummedVec`] checks its contents on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerifyPolicy {
+    /// Only when [`ChecksummedVec::verify`] is called.
+    Manual,
+    /// Before every mutation, and when dropped, panicking on a mismatch.
+    Always,
+}
+
+/// Returned by [`ChecksummedVec::verify`] when the contents no longer match
+/// the checksum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChecksumMismatch {
+    pub expected: u64,
+    pub actual: u64,
+}
+
+impl fmt::Display for ChecksumMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "checksum mismatch: expected {:#018x}, found {:#018x}",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl Error for ChecksumMismatch {}
+
+/// A vector that keeps a checksum of its contents, as a tripwire for memory
+/// corruption from unsafe code elsewhere in the process.
+///
+/// The checksum is a wrapping sum of one hash per element, mixed with its
+/// index, so pushes, pops and single-element writes update it in O(1).
+/// Elements can only be changed through these methods; anything else
+/// writing to the buffer shows up as a mismatch.
+pub struct ChecksummedVec<T: Pod> {
+    vec: MyVec<T>,
+    checksum: u64,
+    policy: VerifyPolicy,
+}
+
+impl<T: Pod> ChecksummedVec<T> {
+    pub fn new(policy: VerifyPolicy) -> Self {
+        Self::from_myvec(MyVec::new(), policy)
+    }
+
+    pub fn from_myvec(vec: MyVec<T>, policy: VerifyPolicy) -> Self {
+        let checksum = checksum_of(&vec);
+
+        Self {
+            vec,
+            checksum,
+            policy,
+        }
+    }
+
+    pub fn checksum(&self) -> u64 {
+        self.checksum
+    }
+
+    /// Recomputes the checksum from scratch and compares it with the one
+    /// maintained along with the contents.
+    pub fn verify(&self) -> Result<(), ChecksumMismatch> {
+        let actual = checksum_of(&self.vec);
+
+        if actual == self.checksum {
+            Ok(())
+        } else {
+            Err(ChecksumMismatch {
+                expected: self.checksum,
+                actual,
+            })
+        }
+    }
+
+    #[cfg(not(no_global_oom_handling))]
+    pub fn push(&mut self, elem: T) {
+        self.auto_verify();
+
+        self.checksum = self
+            .checksum
+            .wrapping_add(element_hash(self.vec.len(), &elem));
+        self.vec.push(elem);
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.auto_verify();
+
+        let elem = self.vec.pop()?;
+        self.checksum = self
+            .checksum
+            .wrapping_sub(element_hash(self.vec.len(), &elem));
+
+        Some(elem)
+    }
+
+    /// Replaces the element at `idx`, returning the old one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn set(&mut self, idx: usize, elem: T) -> T {
+        self.auto_verify();
+
+        let old = mem::replace(&mut self.vec[idx], elem);
+        self.checksum = self
+            .checksum
+            .wrapping_sub(element_hash(idx, &old))
+            .wrapping_add(element_hash(idx, &elem));
+
+        old
+    }
+
+    /// Runs `f` on the elements, then recomputes the whole checksum.
+    pub fn update<R>(&mut self, f: impl FnOnce(&mut [T]) -> R) -> R {
+        self.auto_verify();
+
+        let result = f(&mut self.vec);
+        self.checksum = checksum_of(&self.vec);
+
+        result
+    }
+
+    /// Returns the vector, checking it first under [`VerifyPolicy::Always`].
+    pub fn into_inner(mut self) -> MyVec<T> {
+        self.auto_verify();
+        self.policy = VerifyPolicy::Manual;
+
+        mem::take(&mut self.vec)
+    }
+
+    fn auto_verify(&self) {
+        if self.policy == VerifyPolicy::Always {
+            if let Err(err) = self.verify() {
+                panic!("{err}");
+            }
+        }
+    }
+}
+
+impl<T: Pod> Deref for ChecksummedVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.vec
+    }
+}
+
+impl<T: Pod> Drop for ChecksummedVec<T> {
+    fn drop(&mut self) {
+        if !std::thread::panicking() {
+            self.auto_verify();
+        }
+    }
+}
+
+fn checksum_of<T: Pod>(elems: &[T]) -> u64 {
+    elems.iter().enumerate().fold(0, |sum, (idx, elem)| {
+        sum.wrapping_add(element_hash(idx, elem))
+    })
+}
+
+/// FNV-1a over the element's bytes, seeded with its index and finished with
+/// a splitmix64 round so neighbouring values spread over all 64 bits.
+fn element_hash<T: Pod>(idx: usize, elem: &T) -> u64 {
+    let bytes =
+        unsafe { slice::from_raw_parts(elem as *const T as *const u8, mem::size_of::<T>()) };
+
+    let mut h = 0xcbf2_9ce4_8422_2325 ^ (idx as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+    for &b in bytes {
+        h = (h ^ b as u64).wrapping_mul(0x0000_0100_0000_01b3);
+    }
+
+    h = (h ^ (h >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    h = (h ^ (h >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    h ^ (h >> 31)
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn checksum_tracks_mutations() {
+    let mut v = ChecksummedVec::new(VerifyPolicy::Always);
+    for i in 0..10u32 {
+        v.push(i);
+    }
+    v.set(3, 30);
+    assert_eq!(Some(9), v.pop());
+    v.update(|elems| elems.swap(0, 1));
+    assert_eq!(Ok(()), v.verify());
+    assert_eq!([1, 0, 2, 30], v[..4]);
+
+    // swapping two elements changes the checksum
+    let mut inner = v.into_inner();
+    let raw = inner.as_mut_ptr();
+    let mut w = ChecksummedVec::from_myvec(inner, VerifyPolicy::Manual);
+    let before = w.checksum();
+    w.update(|elems| elems.swap(0, 1));
+    assert_ne!(before, w.checksum());
+
+    // a write that bypasses the wrapper is caught
+    unsafe { *raw.add(5) = 55 };
+    let err = w.verify().unwrap_err();
+    assert_eq!(w.checksum(), err.expected);
+}
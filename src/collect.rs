@@ -0,0 +1,95 @@
+use crate::{MyVec, TryReserveError};
+
+/// Fallible counterpart of `FromIterator`: building the collection reports
+/// allocation failure instead of aborting.
+pub trait TryFromIterator<A>: Sized {
+    fn try_from_iter<I: IntoIterator<Item = A>>(iter: I) -> Result<Self, TryReserveError>;
+}
+
+impl<T> TryFromIterator<T> for MyVec<T> {
+    /// Reserves the iterator's lower size bound up front, then grows as
+    /// needed, all through the fallible APIs.
+    fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, TryReserveError> {
+        let iter = iter.into_iter();
+
+        let mut v = MyVec::new();
+        v.try_reserve(iter.size_hint().0)?;
+
+        for elem in iter {
+            v.try_push(elem)?;
+        }
+
+        Ok(v)
+    }
+}
+
+/// Collection helpers for iterators, producing `MyVec`s.
+pub trait IteratorExt: Iterator + Sized {
+    /// Collects into a `MyVec`, returning an error instead of aborting if
+    /// an allocation fails.
+    fn try_collect_myvec(self) -> Result<MyVec<Self::Item>, TryReserveError> {
+        MyVec::try_from_iter(self)
+    }
+
+    /// Collects the `Ok` values up to the first `Err`, returning them along
+    /// with that error, so the work done before a failure isn't lost.
+    ///
+    /// The iterator is not advanced past the error.
+    #[cfg(not(no_global_oom_handling))]
+    fn collect_until_err<T, E>(self) -> (MyVec<T>, Option<E>)
+    where
+        Self: Iterator<Item = Result<T, E>>,
+    {
+        let mut v = MyVec::new();
+        v.reserve(self.size_hint().0);
+
+        for item in self {
+            match item {
+                Ok(elem) => v.push(elem),
+                Err(err) => return (v, Some(err)),
+            }
+        }
+
+        (v, None)
+    }
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+#[test]
+fn try_collect() {
+    let v = (0..100).map(|i| i * 2).try_collect_myvec().unwrap();
+    assert_eq!(100, v.len());
+    assert_eq!(100, v.capacity());
+    assert_eq!(198, v[99]);
+
+    // an impossible size hint is reported rather than aborting
+    struct Huge;
+    impl Iterator for Huge {
+        type Item = u64;
+        fn next(&mut self) -> Option<u64> {
+            None
+        }
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (usize::MAX, None)
+        }
+    }
+    assert_eq!(
+        Err(TryReserveError::CapacityOverflow),
+        Huge.try_collect_myvec().map(|v| v.len())
+    );
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn collect_until_err_keeps_prefix() {
+    let mut rows = ["1", "2", "x", "4"].iter().map(|s| s.parse::<u32>());
+    let (good, err) = rows.by_ref().collect_until_err();
+    assert_eq!(&[1, 2], &good[..]);
+    assert!(err.is_some());
+    assert_eq!(Some(4), rows.next().map(Result::unwrap));
+
+    let (all, err) = (0..3).map(Ok::<_, ()>).collect_until_err();
+    assert_eq!(&[0, 1, 2], &all[..]);
+    assert_eq!(None, err);
+}
@@ -0,0 +1,203 @@
+use crate::{LruVec, MyVec, Pod};
+
+// the first byte of every sealed block says how the rest is stored
+const RAW: u8 = 0;
+const CODED: u8 = 1;
+
+/// A block compression scheme for [`CompressedVec`].
+///
+/// `decompress` is only ever handed bytes produced by `compress` on the same
+/// codec, and must fill `output`, which is exactly as long as the original
+/// input.
+///
+/// The crate has no dependencies, so it ships no LZ4 or zstd codec; wrap
+/// those crates in an implementation of this trait to use them. The only
+/// built-in codec is [`RunLength`].
+pub trait BlockCodec {
+    fn compress(&self, input: &[u8], output: &mut MyVec<u8>);
+
+    fn decompress(&self, input: &[u8], output: &mut [u8]);
+}
+
+/// Byte-level run-length encoding: cheap, and effective on data with long
+/// runs such as mostly-constant telemetry.
+pub struct RunLength;
+
+impl BlockCodec for RunLength {
+    fn compress(&self, input: &[u8], output: &mut MyVec<u8>) {
+        let mut rest = input;
+
+        while let Some(&byte) = rest.first() {
+            let run = rest.iter().take(255).take_while(|&&b| b == byte).count();
+            output.push(run as u8);
+            output.push(byte);
+            rest = &rest[run..];
+        }
+    }
+
+    fn decompress(&self, input: &[u8], output: &mut [u8]) {
+        let mut pos = 0;
+
+        for pair in input.chunks_exact(2) {
+            let run = pair[0] as usize;
+            output[pos..pos + run].fill(pair[1]);
+            pos += run;
+        }
+
+        assert_eq!(output.len(), pos, "corrupt run-length block");
+    }
+}
+
+/// An append-only vector that keeps its elements compressed in fixed-size
+/// blocks, decompressing them on demand.
+///
+/// Appends go to an uncompressed tail block, which is compressed once full.
+/// A block the codec can't shrink is kept as it is, so incompressible data
+/// costs one byte per block more than storing it plainly.
+/// Reads from sealed blocks decompress the whole block and keep it in a
+/// small LRU cache, so scans and clustered reads only pay for each block
+/// once.
+pub struct CompressedVec<T, C> {
+    codec: C,
+    block_len: usize,
+    blocks: MyVec<MyVec<u8>>,
+    tail: MyVec<T>,
+    cache: Option<LruVec<usize, MyVec<T>>>, // `None` when caching is off
+    len: usize,
+}
+
+impl<T: Pod, C: BlockCodec> CompressedVec<T, C> {
+    /// Creates an empty vector compressing `block_len` elements at a time,
+    /// and caching up to `cached_blocks` decompressed blocks; with
+    /// `cached_blocks == 0` every read of a sealed block decompresses it
+    /// afresh.
+    pub fn new(codec: C, block_len: usize, cached_blocks: usize) -> Self {
+        assert!(block_len > 0, "block length must be non-zero");
+
+        Self {
+            codec,
+            block_len,
+            blocks: MyVec::new(),
+            tail: MyVec::with_capacity(block_len),
+            cache: (cached_blocks > 0).then(|| LruVec::new(cached_blocks)),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the total size of the sealed, compressed blocks.
+    pub fn compressed_bytes(&self) -> usize {
+        self.blocks.iter().map(|block| block.len()).sum()
+    }
+
+    pub fn push(&mut self, elem: T) {
+        self.tail.push(elem);
+        self.len += 1;
+
+        if self.tail.len() == self.block_len {
+            let raw = self.tail.as_bytes();
+            let mut block = MyVec::new();
+            block.push(CODED);
+            self.codec.compress(raw, &mut block);
+
+            if block.len() > raw.len() {
+                block.clear();
+                block.push(RAW);
+                block.extend_from_slice(raw);
+            }
+            self.blocks.push(block);
+            self.tail.clear();
+        }
+    }
+
+    pub fn get(&mut self, idx: usize) -> Option<T> {
+        if idx >= self.len {
+            return None;
+        }
+
+        let (block, offset) = (idx / self.block_len, idx % self.block_len);
+
+        if block == self.blocks.len() {
+            return Some(self.tail[offset]);
+        }
+
+        if let Some(values) = self.cache.as_mut().and_then(|cache| cache.get(&block)) {
+            return Some(values[offset]);
+        }
+
+        let mut values = MyVec::<T>::zeroed(self.block_len);
+        match self.blocks[block].split_first() {
+            Some((&RAW, raw)) => values.as_bytes_mut().copy_from_slice(raw),
+            Some((&CODED, coded)) => self.codec.decompress(coded, values.as_bytes_mut()),
+            _ => unreachable!("sealed blocks start with their tag"),
+        }
+        let value = values[offset];
+        if let Some(cache) = &mut self.cache {
+            cache.put(block, values);
+        }
+
+        Some(value)
+    }
+}
+
+#[test]
+fn compressed_vec_reads_back() {
+    let mut v = CompressedVec::new(RunLength, 64, 2);
+    let value = |i: usize| {
+        if i < 500 {
+            0
+        } else {
+            u32::MAX - (i % 2) as u32
+        }
+    };
+    for i in 0..1000 {
+        v.push(value(i));
+    }
+
+    assert_eq!(1000, v.len());
+    // 15 sealed blocks of 256 bytes each
+    assert!(v.compressed_bytes() < 15 * 256 / 2);
+
+    for i in (0..1000).rev() {
+        assert_eq!(Some(value(i)), v.get(i));
+    }
+    assert_eq!(None, v.get(1000));
+
+    let mut uncached = CompressedVec::new(RunLength, 4, 0);
+    for i in 0..10u8 {
+        uncached.push(i);
+    }
+    assert_eq!(Some(5), uncached.get(5));
+    assert_eq!(Some(5), uncached.get(5));
+}
+
+#[test]
+fn incompressible_blocks_stay_raw() {
+    let mut v = CompressedVec::new(RunLength, 64, 0);
+    for i in 0..640u32 {
+        v.push(i.wrapping_mul(0x9e37_79b9));
+    }
+
+    // run-length coding would double these blocks
+    assert_eq!(10 * (64 * 4 + 1), v.compressed_bytes());
+    assert_eq!(Some(100u32.wrapping_mul(0x9e37_79b9)), v.get(100));
+}
+
+#[test]
+fn run_length_long_runs() {
+    let input = [7u8; 600];
+    let mut packed = MyVec::new();
+    RunLength.compress(&input, &mut packed);
+    assert_eq!(6, packed.len());
+
+    let mut output = [0u8; 600];
+    RunLength.decompress(&packed, &mut output);
+    assert_eq!(input, output);
+}
@@ -0,0 +1,74 @@
+use std::mem;
+
+use crate::MyVec;
+
+/// A front and a back buffer for frame-style pipelines: the producer fills
+/// the back one, [`swap`](Self::swap) publishes it as the front, and the old
+/// front comes back to be reused, keeping both allocations alive.
+#[derive(Default)]
+pub struct DoubleBuffered<B> {
+    front: B,
+    back: B,
+}
+
+impl<B> DoubleBuffered<B> {
+    pub fn new(front: B, back: B) -> Self {
+        Self { front, back }
+    }
+
+    pub fn front(&self) -> &B {
+        &self.front
+    }
+
+    pub fn front_mut(&mut self) -> &mut B {
+        &mut self.front
+    }
+
+    pub fn back(&self) -> &B {
+        &self.back
+    }
+
+    pub fn back_mut(&mut self) -> &mut B {
+        &mut self.back
+    }
+
+    /// Exchanges the buffers, making the back one the new front.
+    pub fn swap(&mut self) {
+        mem::swap(&mut self.front, &mut self.back);
+    }
+}
+
+impl<T> DoubleBuffered<MyVec<T>> {
+    /// Empties the back buffer, keeping its capacity.
+    pub fn clear_back(&mut self) {
+        self.back.clear();
+    }
+
+    /// Clears the back buffer and returns it for refilling.
+    pub fn begin_frame(&mut self) -> &mut MyVec<T> {
+        self.back.clear();
+        &mut self.back
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn double_buffered_reuses_both() {
+    let mut frames = DoubleBuffered::<MyVec<u32>>::default();
+
+    frames.back_mut().push(1);
+    frames.swap();
+    assert_eq!(&[1], &frames.front()[..]);
+
+    let back = frames.begin_frame();
+    back.push(2);
+    back.push(3);
+    frames.swap();
+    assert_eq!(&[2, 3], &frames.front()[..]);
+    let reused = frames.back().as_ptr();
+
+    frames.clear_back();
+    assert!(frames.back().is_empty());
+    frames.back_mut().push(4);
+    assert_eq!(reused, frames.back().as_ptr());
+}
@@ -0,0 +1,108 @@
+use std::ops::Range;
+use std::ptr;
+
+use crate::MyVec;
+
+impl<T> MyVec<T> {
+    /// Removes the elements in `range`, yielding them as owned vectors of
+    /// `chunk_len` elements each; the last chunk may be shorter.
+    ///
+    /// The elements after `range` are shifted down once, when the iterator
+    /// is dropped. Chunks that are never yielded are dropped there too.
+    pub fn drain_chunks(&mut self, range: Range<usize>, chunk_len: usize) -> DrainChunks<'_, T> {
+        assert!(chunk_len > 0, "chunk length must be non-zero");
+        assert!(
+            range.start <= range.end && range.end <= self.len,
+            "range out of bounds"
+        );
+
+        let tail_len = self.len - range.end;
+        // if the iterator is leaked, the drained range and the tail leak too
+        self.len = range.start;
+
+        DrainChunks {
+            vec: self,
+            next: range.start,
+            end: range.end,
+            tail_len,
+            chunk_len,
+        }
+    }
+}
+
+pub struct DrainChunks<'a, T> {
+    vec: &'a mut MyVec<T>,
+    next: usize,
+    end: usize,
+    tail_len: usize,
+    chunk_len: usize,
+}
+
+impl<T> Iterator for DrainChunks<'_, T> {
+    type Item = MyVec<T>;
+
+    fn next(&mut self) -> Option<MyVec<T>> {
+        if self.next == self.end {
+            return None;
+        }
+
+        let len = self.chunk_len.min(self.end - self.next);
+        let mut chunk = MyVec::with_capacity(len);
+
+        unsafe {
+            ptr::copy_nonoverlapping(self.vec.ptr().add(self.next), chunk.ptr(), len);
+        }
+        chunk.len = len;
+        chunk.record_len();
+        self.next += len;
+
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let chunks = (self.end - self.next).div_ceil(self.chunk_len);
+        (chunks, Some(chunks))
+    }
+}
+
+impl<T> ExactSizeIterator for DrainChunks<'_, T> {}
+
+impl<T> Drop for DrainChunks<'_, T> {
+    fn drop(&mut self) {
+        let start = self.vec.len;
+
+        unsafe {
+            let rest = self.vec.ptr().add(self.next);
+            let remaining = self.end - self.next;
+            self.next = self.end;
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(rest, remaining));
+
+            let tail = self.vec.ptr().add(self.end);
+            ptr::copy(tail, self.vec.ptr().add(start), self.tail_len);
+        }
+        self.vec.len = start + self.tail_len;
+    }
+}
+
+#[test]
+fn drain_chunks_shifts_tail_once() {
+    let mut v = MyVec::new();
+    for i in 0..10 {
+        v.push(Box::new(i));
+    }
+
+    let mut chunks = v.drain_chunks(1..8, 3);
+    assert_eq!(3, chunks.len());
+    let first = chunks.next().unwrap();
+    assert_eq!(vec![1, 2, 3], first.iter().map(|b| **b).collect::<Vec<_>>());
+    let second = chunks.next().unwrap();
+    assert_eq!(3, second.len());
+    // the final one-element chunk is dropped along with the iterator
+    drop(chunks);
+
+    assert_eq!(vec![0, 8, 9], v.iter().map(|b| **b).collect::<Vec<_>>());
+
+    let sizes: Vec<_> = v.drain_chunks(0..3, 2).map(|c| c.len()).collect();
+    assert_eq!(vec![2, 1], sizes);
+    assert!(v.is_empty());
+}
@@ -0,0 +1,110 @@
+use std::ptr;
+
+use crate::MyVec;
+
+impl<T> MyVec<T> {
+    /// Removes and yields the elements at `start`, `start + step`,
+    /// `start + 2 * step`, and so on.
+    ///
+    /// The survivors are shifted down as the iterator advances, so the
+    /// whole removal is a single pass; dropping the iterator early finishes
+    /// it, dropping the elements it would have yielded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    pub fn drain_step_by(&mut self, start: usize, step: usize) -> DrainStepBy<'_, T> {
+        assert!(step > 0, "step must be non-zero");
+
+        let old_len = self.len;
+        let start = start.min(old_len);
+        // if the iterator is leaked, everything from `start` on leaks too
+        self.len = start;
+
+        DrainStepBy {
+            vec: self,
+            read: start,
+            write: start,
+            next_hit: start,
+            step,
+            old_len,
+        }
+    }
+}
+
+pub struct DrainStepBy<'a, T> {
+    vec: &'a mut MyVec<T>,
+    read: usize,
+    write: usize,
+    next_hit: usize,
+    step: usize,
+    old_len: usize,
+}
+
+impl<T> Iterator for DrainStepBy<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let base = self.vec.ptr();
+
+        while self.read < self.old_len {
+            let read = self.read;
+            self.read += 1;
+
+            unsafe {
+                if read == self.next_hit {
+                    self.next_hit = self.next_hit.saturating_add(self.step);
+                    return Some(ptr::read(base.add(read)));
+                }
+
+                if read != self.write {
+                    ptr::copy_nonoverlapping(base.add(read), base.add(self.write), 1);
+                }
+            }
+            self.write += 1;
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hits = if self.next_hit < self.old_len {
+            (self.old_len - self.next_hit - 1) / self.step + 1
+        } else {
+            0
+        };
+        (hits, Some(hits))
+    }
+}
+
+impl<T> ExactSizeIterator for DrainStepBy<'_, T> {}
+
+impl<T> Drop for DrainStepBy<'_, T> {
+    fn drop(&mut self) {
+        for _ in &mut *self {}
+        self.vec.len = self.write;
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn drain_step_by_downsamples() {
+    let mut v = MyVec::new();
+    for i in 0..10 {
+        v.push(i.to_string());
+    }
+
+    let mut drained = v.drain_step_by(1, 3);
+    assert_eq!(3, drained.len());
+    assert_eq!(Some("1".to_string()), drained.next());
+    assert_eq!(2, drained.len());
+    // 4 and 7 are dropped along with the iterator
+    drop(drained);
+    assert_eq!(["0", "2", "3", "5", "6", "8", "9"], *v);
+
+    assert_eq!(0, v.drain_step_by(7, 1).count());
+    assert_eq!(7, v.len());
+    let evens: Vec<_> = v.drain_step_by(0, 2).collect();
+    assert_eq!(["0", "3", "6", "9"], *evens);
+    assert_eq!(["2", "5", "8"], *v);
+}
@@ -0,0 +1,254 @@
+use crate::MyVec;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The primitive numeric types with vectorized elementwise arithmetic on
+/// [`MyVec`].
+///
+/// Integer arithmetic wraps on overflow, as SIMD lanes do. This trait is
+/// sealed and cannot be implemented outside this crate.
+pub trait Elementwise: Copy + sealed::Sealed {
+    #[doc(hidden)]
+    fn add(a: Self, b: Self) -> Self;
+
+    #[doc(hidden)]
+    fn mul(a: Self, b: Self) -> Self;
+
+    #[doc(hidden)]
+    fn add_assign_slice(dst: &mut [Self], src: &[Self]) {
+        portable::add_assign_slice(dst, src);
+    }
+
+    #[doc(hidden)]
+    fn mul_assign_scalar(dst: &mut [Self], k: Self) {
+        portable::mul_assign_scalar(dst, k);
+    }
+
+    #[doc(hidden)]
+    fn axpy(dst: &mut [Self], a: Self, x: &[Self]) {
+        portable::axpy(dst, a, x);
+    }
+}
+
+impl<T: Elementwise> MyVec<T> {
+    /// Adds `other` to the vector element by element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lengths differ.
+    pub fn add_assign_slice(&mut self, other: &[T]) {
+        assert_eq!(self.len, other.len(), "length mismatch");
+        T::add_assign_slice(self, other);
+    }
+
+    /// Multiplies every element by `k`.
+    pub fn mul_assign_scalar(&mut self, k: T) {
+        T::mul_assign_scalar(self, k);
+    }
+
+    /// Computes `self[i] += a * x[i]` for every element, the BLAS `axpy`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lengths differ.
+    pub fn saxpy(&mut self, a: T, x: &[T]) {
+        assert_eq!(self.len, x.len(), "length mismatch");
+        T::axpy(self, a, x);
+    }
+}
+
+/// Loops over fixed-width chunks with no bounds checks left inside, which
+/// the autovectorizer reliably turns into SIMD.
+mod portable {
+    use super::Elementwise;
+
+    const LANES: usize = 8;
+
+    pub(super) fn add_assign_slice<T: Elementwise>(dst: &mut [T], src: &[T]) {
+        let mut d = dst.chunks_exact_mut(LANES);
+        let mut s = src.chunks_exact(LANES);
+
+        for (d, s) in (&mut d).zip(&mut s) {
+            for i in 0..LANES {
+                d[i] = T::add(d[i], s[i]);
+            }
+        }
+        for (d, &s) in d.into_remainder().iter_mut().zip(s.remainder()) {
+            *d = T::add(*d, s);
+        }
+    }
+
+    pub(super) fn mul_assign_scalar<T: Elementwise>(dst: &mut [T], k: T) {
+        let mut d = dst.chunks_exact_mut(LANES);
+
+        for d in &mut d {
+            for x in d {
+                *x = T::mul(*x, k);
+            }
+        }
+        for x in d.into_remainder() {
+            *x = T::mul(*x, k);
+        }
+    }
+
+    pub(super) fn axpy<T: Elementwise>(dst: &mut [T], a: T, x: &[T]) {
+        let mut d = dst.chunks_exact_mut(LANES);
+        let mut s = x.chunks_exact(LANES);
+
+        for (d, s) in (&mut d).zip(&mut s) {
+            for i in 0..LANES {
+                d[i] = T::add(d[i], T::mul(a, s[i]));
+            }
+        }
+        for (d, &s) in d.into_remainder().iter_mut().zip(s.remainder()) {
+            *d = T::add(*d, T::mul(a, s));
+        }
+    }
+}
+
+macro_rules! impl_elementwise_int {
+    ($($t:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl Elementwise for $t {
+                fn add(a: Self, b: Self) -> Self {
+                    a.wrapping_add(b)
+                }
+
+                fn mul(a: Self, b: Self) -> Self {
+                    a.wrapping_mul(b)
+                }
+            }
+        )*
+    };
+}
+
+impl_elementwise_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+macro_rules! impl_elementwise_float {
+    ($($t:ident),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl Elementwise for $t {
+                fn add(a: Self, b: Self) -> Self {
+                    a + b
+                }
+
+                fn mul(a: Self, b: Self) -> Self {
+                    a * b
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+impl_elementwise_float!(f32, f64);
+
+/// SSE2 is part of the x86_64 baseline, so these need no runtime detection.
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod sse2 {
+    use std::arch::x86_64::*;
+
+    use super::{portable, sealed, Elementwise};
+
+    macro_rules! impl_elementwise_sse2 {
+        ($t:ident, $lanes:expr, $load:ident, $store:ident, $set1:ident, $add:ident, $mul:ident) => {
+            impl sealed::Sealed for $t {}
+
+            impl Elementwise for $t {
+                fn add(a: Self, b: Self) -> Self {
+                    a + b
+                }
+
+                fn mul(a: Self, b: Self) -> Self {
+                    a * b
+                }
+
+                fn add_assign_slice(dst: &mut [Self], src: &[Self]) {
+                    let split = dst.len() - dst.len() % $lanes;
+                    let (head, tail) = dst.split_at_mut(split);
+
+                    for (d, s) in head.chunks_exact_mut($lanes).zip(src.chunks_exact($lanes)) {
+                        // the chunks are exactly one register wide
+                        unsafe {
+                            let sum = $add($load(d.as_ptr()), $load(s.as_ptr()));
+                            $store(d.as_mut_ptr(), sum);
+                        }
+                    }
+                    portable::add_assign_slice(tail, &src[split..]);
+                }
+
+                fn mul_assign_scalar(dst: &mut [Self], k: Self) {
+                    let split = dst.len() - dst.len() % $lanes;
+                    let (head, tail) = dst.split_at_mut(split);
+
+                    unsafe {
+                        let k = $set1(k);
+                        for d in head.chunks_exact_mut($lanes) {
+                            $store(d.as_mut_ptr(), $mul($load(d.as_ptr()), k));
+                        }
+                    }
+                    portable::mul_assign_scalar(tail, k);
+                }
+
+                fn axpy(dst: &mut [Self], a: Self, x: &[Self]) {
+                    let split = dst.len() - dst.len() % $lanes;
+                    let (head, tail) = dst.split_at_mut(split);
+
+                    unsafe {
+                        let va = $set1(a);
+                        for (d, s) in head.chunks_exact_mut($lanes).zip(x.chunks_exact($lanes)) {
+                            let prod = $mul(va, $load(s.as_ptr()));
+                            $store(d.as_mut_ptr(), $add($load(d.as_ptr()), prod));
+                        }
+                    }
+                    portable::axpy(tail, a, &x[split..]);
+                }
+            }
+        };
+    }
+
+    impl_elementwise_sse2!(
+        f32,
+        4,
+        _mm_loadu_ps,
+        _mm_storeu_ps,
+        _mm_set1_ps,
+        _mm_add_ps,
+        _mm_mul_ps
+    );
+    impl_elementwise_sse2!(
+        f64,
+        2,
+        _mm_loadu_pd,
+        _mm_storeu_pd,
+        _mm_set1_pd,
+        _mm_add_pd,
+        _mm_mul_pd
+    );
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn elementwise_matches_scalar() {
+    let x = MyVec::linspace(-1.0f32, 1.0, 19);
+    let mut y = MyVec::from_elem(0.5f32, 19);
+
+    y.saxpy(2.0, &x);
+    y.add_assign_slice(&x);
+    y.mul_assign_scalar(-1.0);
+    for i in 0..19 {
+        assert_eq!(-(0.5 + 2.0 * x[i] + x[i]), y[i]);
+    }
+
+    let mut ints = MyVec::arange(0u8, 11, 1);
+    ints.mul_assign_scalar(30);
+    ints.saxpy(1, &[1; 11]);
+    assert_eq!([1, 31, 61, 91, 121, 151, 181, 211, 241, 15, 45], *ints);
+}
@@ -0,0 +1,78 @@
+use crate::MyVec;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The integer types whose byte order [`MyVec`] can convert in place.
+///
+/// This trait is sealed and cannot be implemented outside this crate.
+pub trait ByteSwap: Copy + sealed::Sealed {
+    #[doc(hidden)]
+    fn swap_bytes(self) -> Self;
+}
+
+macro_rules! impl_byte_swap {
+    ($($t:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl ByteSwap for $t {
+                fn swap_bytes(self) -> Self {
+                    <$t>::swap_bytes(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_byte_swap!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<T: ByteSwap> MyVec<T> {
+    /// Reverses the byte order of every element.
+    ///
+    /// A plain loop over the whole buffer, which compiles down to wide
+    /// shuffles rather than one swap per element.
+    pub fn swap_bytes_in_place(&mut self) {
+        for x in self.iter_mut() {
+            *x = x.swap_bytes();
+        }
+    }
+
+    /// Converts every element from native to little-endian byte order; as
+    /// the conversion is its own inverse, this also converts back.
+    pub fn to_le_in_place(&mut self) {
+        if cfg!(target_endian = "big") {
+            self.swap_bytes_in_place();
+        }
+    }
+
+    /// Converts every element from native to big-endian byte order; as the
+    /// conversion is its own inverse, this also converts back.
+    pub fn to_be_in_place(&mut self) {
+        if cfg!(target_endian = "little") {
+            self.swap_bytes_in_place();
+        }
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn byte_order_round_trips() {
+    let mut v = MyVec::new();
+    for x in [0x0102_0304u32, 0xdead_beef, 7] {
+        v.push(x);
+    }
+
+    v.to_be_in_place();
+    assert_eq!(&[1, 2, 3, 4], &v.as_bytes()[..4]);
+    v.to_be_in_place();
+    assert_eq!([0x0102_0304, 0xdead_beef, 7], *v);
+
+    v.swap_bytes_in_place();
+    assert_eq!([0x0403_0201, 0xefbe_adde, 0x0700_0000], *v);
+
+    v.to_le_in_place();
+    v.to_le_in_place();
+    assert_eq!(0x0403_0201, v[0]);
+}
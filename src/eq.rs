@@ -0,0 +1,58 @@
+use crate::{MyVec, Pod};
+
+/// Compares element by element, through the slice implementation, which
+/// core already lowers to a single `memcmp` for integers, `bool` and
+/// `char`.
+impl<T: PartialEq<U>, U> PartialEq<MyVec<U>> for MyVec<T> {
+    fn eq(&self, other: &MyVec<U>) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<T: Eq> Eq for MyVec<T> {}
+
+impl<T: PartialEq<U>, U> PartialEq<[U]> for MyVec<T> {
+    fn eq(&self, other: &[U]) -> bool {
+        self[..] == *other
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U; N]> for MyVec<T> {
+    fn eq(&self, other: &[U; N]) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<T: Pod> MyVec<T> {
+    /// Compares the raw bytes of both vectors with one `memcmp`, for `Pod`
+    /// types such as user structs, which `==` compares field by field.
+    ///
+    /// For floats this is bit equality, not `==`: `NaN` equals an identical
+    /// `NaN`, and `0.0` differs from `-0.0`.
+    pub fn bytes_eq(&self, other: &MyVec<T>) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn equality() {
+    let mut a = MyVec::new();
+    let mut b = MyVec::with_capacity(10);
+    for i in 0..5u32 {
+        a.push([i; 3]);
+        b.push([i; 3]);
+    }
+
+    assert!(a == b);
+    assert!(a.bytes_eq(&b));
+    assert!(a == [[0; 3], [1; 3], [2; 3], [3; 3], [4; 3]]);
+    b[4][2] = 9;
+    assert!(a != b);
+    assert!(!a.bytes_eq(&b));
+
+    let mut nan = MyVec::new();
+    nan.push(f64::NAN);
+    assert!(nan != nan.clone());
+    assert!(nan.bytes_eq(&nan.clone()));
+}
@@ -0,0 +1,57 @@
+use std::alloc::Layout;
+use std::error::Error;
+use std::fmt;
+
+/// The error returned by the fallible (`try_*`) allocation APIs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TryReserveError {
+    /// The requested capacity exceeds `isize::MAX` bytes or overflows `usize`.
+    CapacityOverflow,
+    /// The allocator failed to provide memory for `layout`.
+    AllocError { layout: Layout },
+    /// The vector needed to allocate inside a
+    /// [`no_alloc_scope`](crate::no_alloc_scope).
+    AllocDisallowed,
+    /// The allocation would exceed the vector's attached memory budget.
+    #[cfg(feature = "memory-budget")]
+    BudgetExceeded,
+}
+
+impl TryReserveError {
+    /// Reports the error the way the infallible APIs do: a panic for capacity
+    /// overflow, the global OOM handler for allocator failure.
+    #[cfg(not(no_global_oom_handling))]
+    pub(crate) fn handle(self) -> ! {
+        match self {
+            TryReserveError::CapacityOverflow => panic!("capacity overflow"),
+            TryReserveError::AllocError { layout } => std::alloc::handle_alloc_error(layout),
+            TryReserveError::AllocDisallowed => panic!("allocation inside no_alloc_scope"),
+            #[cfg(feature = "memory-budget")]
+            TryReserveError::BudgetExceeded => panic!("memory budget exceeded"),
+        }
+    }
+}
+
+impl fmt::Display for TryReserveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("memory allocation failed")?;
+
+        match self {
+            TryReserveError::CapacityOverflow => {
+                f.write_str(" because the computed capacity exceeded the collection's maximum")
+            }
+            TryReserveError::AllocError { .. } => {
+                f.write_str(" because the memory allocator returned an error")
+            }
+            TryReserveError::AllocDisallowed => {
+                f.write_str(" because allocation is disallowed in this scope")
+            }
+            #[cfg(feature = "memory-budget")]
+            TryReserveError::BudgetExceeded => {
+                f.write_str(" because the memory budget was exhausted")
+            }
+        }
+    }
+}
+
+impl Error for TryReserveError {}
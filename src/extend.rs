@@ -0,0 +1,55 @@
+use crate::MyVec;
+
+impl<T> MyVec<T> {
+    /// Moves the elements of every vector in `vecs` onto the end, in order,
+    /// after a single reservation for all of them.
+    ///
+    /// An iterator can only be walked once, so the lengths can't be totalled
+    /// up front. Instead the source vectors are moved into a temporary
+    /// `MyVec<MyVec<T>>`. That costs one extra allocation, sized from the
+    /// iterator's `size_hint`, holding just each source's header and not its
+    /// elements. The elements are then moved over in bulk and the sources
+    /// freed.
+    pub fn extend_from_vecs(&mut self, vecs: impl IntoIterator<Item = MyVec<T>>) {
+        let vecs = vecs.into_iter();
+        let mut sources = MyVec::with_capacity(vecs.size_hint().0);
+        for vec in vecs {
+            sources.push(vec);
+        }
+
+        let total = sources.iter().map(|vec| vec.len).sum();
+        self.reserve(total);
+
+        for mut vec in sources {
+            self.append(&mut vec);
+        }
+    }
+}
+
+impl<T> Extend<MyVec<T>> for MyVec<T> {
+    fn extend<I: IntoIterator<Item = MyVec<T>>>(&mut self, vecs: I) {
+        self.extend_from_vecs(vecs);
+    }
+}
+
+#[test]
+fn extend_from_vecs_reserves_once() {
+    let mut parts = MyVec::new();
+    for i in 0..4 {
+        let mut part = MyVec::new();
+        for j in 0..=i {
+            part.push(format!("{i}.{j}"));
+        }
+        parts.push(part);
+    }
+
+    let mut flat = MyVec::new();
+    flat.push("start".to_string());
+    flat.extend_from_vecs(parts);
+    assert_eq!(11, flat.len());
+    assert_eq!(11, flat.capacity());
+    assert_eq!(["start", "0.0", "1.0", "1.1", "2.0"], flat[..5]);
+
+    flat.extend([MyVec::new(), MyVec::from_elem("end".to_string(), 2)]);
+    assert_eq!(["3.3", "end", "end"], flat[10..]);
+}
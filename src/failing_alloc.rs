@@ -0,0 +1,88 @@
+//! Deterministic allocation-failure injection for exercising OOM paths.
+//!
+//! Arming the hook with [`fail_nth`] makes the `n`th allocation or
+//! reallocation performed by a `MyVec` on the current thread fail, as if the
+//! allocator had returned null. The fallible APIs then report
+//! [`TryReserveError::AllocError`](crate::TryReserveError::AllocError) and the
+//! infallible ones go to the global OOM handler.
+//!
+//! Only allocations made by this crate's vectors are counted; other
+//! allocations on the thread are unaffected.
+
+use std::cell::Cell;
+use std::marker::PhantomData;
+
+thread_local! {
+    // allocations left before the failing one, `None` when disarmed
+    static COUNTDOWN: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+/// Disarms the hook when dropped.
+pub struct FailGuard {
+    _not_send: PhantomData<*const ()>, // the hook is per thread
+}
+
+/// Makes the `n`th upcoming allocation on this thread fail, with `1` meaning
+/// the very next one. Every allocation after that succeeds again.
+pub fn fail_nth(n: usize) -> FailGuard {
+    assert!(n > 0, "n is 1-based");
+
+    COUNTDOWN.with(|c| c.set(Some(n - 1)));
+
+    FailGuard {
+        _not_send: PhantomData,
+    }
+}
+
+impl Drop for FailGuard {
+    fn drop(&mut self) {
+        COUNTDOWN.with(|c| c.set(None));
+    }
+}
+
+pub(crate) fn should_fail() -> bool {
+    COUNTDOWN.with(|c| match c.get() {
+        Some(0) => {
+            c.set(None);
+            true
+        }
+        Some(n) => {
+            c.set(Some(n - 1));
+            false
+        }
+        None => false,
+    })
+}
+
+#[test]
+fn fails_the_nth_allocation() {
+    use crate::{MyVec, TryReserveError};
+
+    let mut v = MyVec::new();
+    let guard = fail_nth(3);
+    v.try_push(0u32).unwrap(); // allocates 1
+    v.try_push(1).unwrap(); // grows to 2
+    assert!(matches!(
+        v.try_push(2),
+        Err(TryReserveError::AllocError { .. })
+    ));
+    assert_eq!(&[0, 1], &v[..]);
+    assert_eq!(2, v.capacity());
+
+    v.try_push(2).unwrap();
+    drop(guard);
+
+    let _guard = fail_nth(1);
+    assert!(MyVec::<u8>::try_with_capacity(8).is_err());
+    assert!(MyVec::<u8>::try_with_capacity(8).is_ok());
+}
+
+#[test]
+fn guard_disarms_on_drop() {
+    use crate::MyVec;
+
+    drop(fail_nth(1));
+
+    let mut v = MyVec::new();
+    v.try_push(1u8).unwrap();
+}
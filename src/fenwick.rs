@@ -0,0 +1,102 @@
+use std::ops::{Add, Range, Sub};
+
+use crate::MyVec;
+
+/// A Fenwick (binary indexed) tree maintaining prefix sums under point
+/// updates in O(log n).
+///
+/// Slot `i` (1-based) holds the sum of the `i & i.wrapping_neg()` elements
+/// ending at `i`; the tree takes no more room than the values themselves.
+pub struct FenwickTree<T> {
+    tree: MyVec<T>,
+}
+
+impl<T: Copy + Default + Add<Output = T> + Sub<Output = T>> FenwickTree<T> {
+    /// Creates a tree of `len` zero (`T::default()`) elements.
+    pub fn new(len: usize) -> Self {
+        let mut tree = MyVec::with_capacity(len);
+        for _ in 0..len {
+            tree.push(T::default());
+        }
+
+        Self { tree }
+    }
+
+    pub fn len(&self) -> usize {
+        self.tree.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tree.is_empty()
+    }
+
+    /// Adds `delta` to the element at `idx`.
+    pub fn add(&mut self, idx: usize, delta: T) {
+        assert!(idx < self.len(), "index out of bounds");
+
+        let mut i = idx + 1;
+        while i <= self.len() {
+            self.tree[i - 1] = self.tree[i - 1] + delta;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Returns the sum of the first `idx` elements.
+    pub fn prefix_sum(&self, idx: usize) -> T {
+        assert!(idx <= self.len(), "index out of bounds");
+
+        let mut sum = T::default();
+        let mut i = idx;
+        while i > 0 {
+            sum = sum + self.tree[i - 1];
+            i -= i & i.wrapping_neg();
+        }
+
+        sum
+    }
+
+    pub fn range_sum(&self, range: Range<usize>) -> T {
+        assert!(range.start <= range.end, "range out of bounds");
+
+        self.prefix_sum(range.end) - self.prefix_sum(range.start)
+    }
+}
+
+/// Builds the tree in O(n), reusing the vector's allocation.
+impl<T: Copy + Default + Add<Output = T> + Sub<Output = T>> From<MyVec<T>> for FenwickTree<T> {
+    fn from(mut tree: MyVec<T>) -> Self {
+        let len = tree.len();
+
+        for i in 1..=len {
+            let parent = i + (i & i.wrapping_neg());
+            if parent <= len {
+                tree[parent - 1] = tree[parent - 1] + tree[i - 1];
+            }
+        }
+
+        Self { tree }
+    }
+}
+
+#[test]
+fn fenwick_prefix_sums() {
+    let mut values = MyVec::new();
+    for v in [3, 1, 4, 1, 5, 9, 2, 6] {
+        values.push(v);
+    }
+
+    let mut tree = FenwickTree::from(values);
+    assert_eq!(0, tree.prefix_sum(0));
+    assert_eq!(8, tree.prefix_sum(3));
+    assert_eq!(31, tree.prefix_sum(8));
+    assert_eq!(15, tree.range_sum(3..6));
+
+    tree.add(4, -5);
+    assert_eq!(10, tree.range_sum(3..6));
+
+    let mut counts = FenwickTree::new(4);
+    counts.add(3, 2u64);
+    counts.add(0, 1);
+    assert_eq!(1, counts.prefix_sum(3));
+    assert_eq!(3, counts.prefix_sum(4));
+}
@@ -0,0 +1,150 @@
+use std::marker::PhantomData;
+use std::mem::{self, ManuallyDrop};
+use std::ptr;
+
+use crate::{MyVec, RawVec};
+
+impl<T> MyVec<T> {
+    /// Passes every element by value to `f`, keeping the `Some` results in
+    /// place of the originals, in order, in a single pass.
+    ///
+    /// If `f` panics, the vector is left empty and every element is dropped.
+    pub fn retain_map<F>(&mut self, f: F)
+    where
+        F: FnMut(T) -> Option<T>,
+    {
+        *self = mem::take(self).filter_map_in_place(f);
+    }
+
+    /// Like [`retain_map`](Self::retain_map), but mapping into another type
+    /// with the same size and alignment, reusing the allocation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `U` and `T` differ in size or alignment.
+    pub fn filter_map_in_place<U, F>(self, mut f: F) -> MyVec<U>
+    where
+        F: FnMut(T) -> Option<U>,
+    {
+        assert!(
+            mem::size_of::<T>() == mem::size_of::<U>()
+                && mem::align_of::<T>() == mem::align_of::<U>(),
+            "T and U must have the same layout"
+        );
+
+        let this = ManuallyDrop::new(self);
+        let mut guard = Guard::<T, U> {
+            buf: unsafe { ptr::read(&this.buf) },
+            read: 0,
+            write: 0,
+            len: this.len,
+            _marker: PhantomData,
+        };
+
+        let src = guard.buf.ptr.as_ptr();
+        let dst = src as *mut U;
+
+        // the write cursor never passes the read cursor, so outputs only
+        // overwrite slots whose element was already taken
+        while guard.read < guard.len {
+            let elem = unsafe { ptr::read(src.add(guard.read)) };
+            guard.read += 1;
+
+            if let Some(out) = f(elem) {
+                unsafe { ptr::write(dst.add(guard.write), out) };
+                guard.write += 1;
+            }
+        }
+
+        let guard = ManuallyDrop::new(guard);
+        let buf = unsafe { ptr::read(&guard.buf) };
+        let cap = buf.cap;
+
+        MyVec {
+            buf: unsafe { buf.cast(cap) },
+            len: guard.write,
+        }
+    }
+}
+
+// Owns the buffer mid-pass: `write` mapped `U`s at the front, the untouched
+// `T`s from `read` to `len`, and nothing initialized in between.
+struct Guard<T, U> {
+    buf: RawVec<T>,
+    read: usize,
+    write: usize,
+    len: usize,
+    _marker: PhantomData<U>,
+}
+
+impl<T, U> Drop for Guard<T, U> {
+    fn drop(&mut self) {
+        let ptr = self.buf.ptr.as_ptr();
+
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(ptr as *mut U, self.write));
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                ptr.add(self.read),
+                self.len - self.read,
+            ));
+        }
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn retain_map_in_place() {
+    let mut v = MyVec::new();
+    for i in 0..10 {
+        v.push(Box::new(i));
+    }
+    let ptr = v.as_ptr();
+
+    v.retain_map(|b| {
+        if *b % 3 == 0 {
+            Some(Box::new(*b * 10))
+        } else {
+            None
+        }
+    });
+    assert_eq!(ptr, v.as_ptr());
+    assert_eq!(
+        vec![0, 30, 60, 90],
+        v.iter().map(|b| **b).collect::<Vec<_>>()
+    );
+
+    let mut nums = MyVec::new();
+    for i in 0..6u32 {
+        nums.push(i);
+    }
+    let floats: MyVec<f32> = nums.filter_map_in_place(|n| (n % 2 == 1).then(|| n as f32 / 2.0));
+    assert_eq!(&[0.5, 1.5, 2.5], &floats[..]);
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn retain_map_panic_drops_everything() {
+    use std::panic::{self, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    let tracker = Rc::new(());
+    let mut v = MyVec::new();
+    for _ in 0..6 {
+        v.push(Rc::clone(&tracker));
+    }
+
+    let mut seen = 0;
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        v.retain_map(|rc| {
+            seen += 1;
+            if seen == 4 {
+                panic!("boom");
+            }
+            Some(rc)
+        })
+    }));
+
+    assert!(result.is_err());
+    assert!(v.is_empty());
+    assert_eq!(1, Rc::strong_count(&tracker));
+}
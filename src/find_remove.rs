@@ -0,0 +1,36 @@
+use crate::MyVec;
+
+impl<T> MyVec<T> {
+    /// Removes and returns the first element matching `pred`, shifting the
+    /// ones after it down.
+    pub fn find_remove(&mut self, pred: impl FnMut(&T) -> bool) -> Option<T> {
+        let idx = self.iter().position(pred)?;
+        Some(self.remove(idx))
+    }
+
+    /// Like [`MyVec::find_remove`], but fills the gap with the last element
+    /// instead, in O(1) after the search.
+    pub fn find_swap_remove(&mut self, pred: impl FnMut(&T) -> bool) -> Option<T> {
+        let idx = self.iter().position(pred)?;
+        Some(self.swap_remove(idx))
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn find_remove_first_match() {
+    let mut v = MyVec::new();
+    for s in ["a", "bb", "c", "dd", "e"] {
+        v.push(s.to_string());
+    }
+
+    assert_eq!(Some("bb".to_string()), v.find_remove(|s| s.len() == 2));
+    assert_eq!(["a", "c", "dd", "e"], *v);
+
+    assert_eq!(Some("a".to_string()), v.find_swap_remove(|s| s == "a"));
+    assert_eq!(["e", "c", "dd"], *v);
+    assert_eq!(Some("dd".to_string()), v.find_swap_remove(|s| s == "dd"));
+    assert_eq!(["e", "c"], *v);
+
+    assert_eq!(None, v.find_remove(|s| s.is_empty()));
+}
@@ -0,0 +1,115 @@
+use std::cmp::Ordering;
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::MyVec;
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// `f32` and `f64`, for the float-specific methods on [`MyVec`].
+///
+/// This trait is sealed and cannot be implemented outside this crate.
+pub trait Float:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + sealed::Sealed
+{
+    #[doc(hidden)]
+    fn from_usize(n: usize) -> Self;
+
+    #[doc(hidden)]
+    fn is_nan(self) -> bool;
+
+    #[doc(hidden)]
+    fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
+macro_rules! impl_float {
+    ($($t:ty),*) => {
+        $(
+            impl Float for $t {
+                fn from_usize(n: usize) -> Self {
+                    n as $t
+                }
+
+                fn is_nan(self) -> bool {
+                    <$t>::is_nan(self)
+                }
+
+                fn total_cmp(&self, other: &Self) -> Ordering {
+                    <$t>::total_cmp(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
+
+/// Orders by `total_cmp`, except that every NaN sorts after every number,
+/// whatever its sign bit.
+fn nan_last<T: Float>(a: &T, b: &T) -> Ordering {
+    a.is_nan().cmp(&b.is_nan()).then_with(|| a.total_cmp(b))
+}
+
+impl<T: Float> MyVec<T> {
+    /// Sorts the floats in ascending order, with `-0.0` before `0.0` and all
+    /// NaNs at the end.
+    #[cfg(not(no_global_oom_handling))]
+    pub fn sort_floats(&mut self) {
+        self.sort_by(nan_last);
+    }
+
+    /// Like [`MyVec::sort_floats`], but unstable, so faster and without the
+    /// allocation.
+    pub fn sort_unstable_floats(&mut self) {
+        self.sort_unstable_by(nan_last);
+    }
+
+    /// Returns the smallest non-NaN element, or `None` if there is none.
+    pub fn min_float(&self) -> Option<T> {
+        self.iter()
+            .copied()
+            .filter(|x| !x.is_nan())
+            .min_by(T::total_cmp)
+    }
+
+    /// Returns the largest non-NaN element, or `None` if there is none.
+    pub fn max_float(&self) -> Option<T> {
+        self.iter()
+            .copied()
+            .filter(|x| !x.is_nan())
+            .max_by(T::total_cmp)
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn float_sort_puts_nans_last() {
+    let mut v = MyVec::new();
+    for x in [3.0, -f64::NAN, 0.0, f64::NAN, -0.0, f64::NEG_INFINITY, 1.5] {
+        v.push(x);
+    }
+
+    assert_eq!(Some(f64::NEG_INFINITY), v.min_float());
+    assert_eq!(Some(3.0), v.max_float());
+
+    v.sort_unstable_floats();
+    assert_eq!([f64::NEG_INFINITY, -0.0, 0.0, 1.5, 3.0], v[..5]);
+    assert!(v[..5].iter().all(|x| !x.is_nan()));
+    assert!(v[0..2].iter().all(|x| x.is_sign_negative()));
+    assert!(v[5..].iter().all(|x| x.is_nan()));
+
+    let mut only_nans = MyVec::new();
+    only_nans.push(f32::NAN);
+    only_nans.sort_floats();
+    assert_eq!(None, only_nans.max_float());
+}
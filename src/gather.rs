@@ -0,0 +1,86 @@
+use crate::MyVec;
+
+impl<T: Clone> MyVec<T> {
+    /// Returns a new vector holding clones of the elements at `indices`, in
+    /// that order.
+    ///
+    /// # Panics
+    ///
+    /// Panics, before cloning anything, if any index is out of bounds.
+    #[cfg(not(no_global_oom_handling))]
+    pub fn gather(&self, indices: &[usize]) -> MyVec<T> {
+        check_indices(indices, self.len);
+        unsafe { self.gather_unchecked(indices) }
+    }
+
+    /// Like [`MyVec::gather`], without the bounds checks.
+    ///
+    /// # Safety
+    ///
+    /// Every index must be less than `self.len()`.
+    #[cfg(not(no_global_oom_handling))]
+    pub unsafe fn gather_unchecked(&self, indices: &[usize]) -> MyVec<T> {
+        let mut out = MyVec::<T>::with_capacity(indices.len());
+
+        for &i in indices {
+            // capacity is reserved, and `len` is bumped after each write so a
+            // panicking clone drops only what was gathered so far
+            out.ptr().add(out.len).write(self.get_unchecked(i).clone());
+            out.len += 1;
+        }
+        out.record_len();
+
+        out
+    }
+
+    /// Overwrites the element at each of `indices` with a clone of the
+    /// corresponding element of `values`; later duplicates win.
+    ///
+    /// # Panics
+    ///
+    /// Panics, before writing anything, if the lengths differ or any index
+    /// is out of bounds.
+    pub fn scatter(&mut self, indices: &[usize], values: &[T]) {
+        assert_eq!(indices.len(), values.len(), "length mismatch");
+        check_indices(indices, self.len);
+
+        unsafe { self.scatter_unchecked(indices, values) }
+    }
+
+    /// Like [`MyVec::scatter`], without the bounds checks.
+    ///
+    /// # Safety
+    ///
+    /// Every index must be less than `self.len()`, and `values` must be at
+    /// least as long as `indices`.
+    pub unsafe fn scatter_unchecked(&mut self, indices: &[usize], values: &[T]) {
+        for (k, &i) in indices.iter().enumerate() {
+            self.get_unchecked_mut(i)
+                .clone_from(values.get_unchecked(k));
+        }
+    }
+}
+
+fn check_indices(indices: &[usize], len: usize) {
+    if let Some(&i) = indices.iter().find(|&&i| i >= len) {
+        panic!("index {i} out of bounds for length {len}");
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn gather_then_scatter() {
+    let mut v = MyVec::new();
+    for s in ["a", "b", "c", "d", "e"] {
+        v.push(s.to_string());
+    }
+
+    let picked = v.gather(&[4, 0, 4, 2]);
+    assert_eq!(["e", "a", "e", "c"], *picked);
+
+    v.scatter(&[1, 3], &picked[2..]);
+    assert_eq!(["a", "e", "c", "c", "e"], *v);
+
+    let result = std::panic::catch_unwind(move || v.gather(&[0, 5]).len());
+    assert!(result.is_err());
+}
@@ -0,0 +1,113 @@
+use std::iter::Chain;
+use std::mem;
+use std::slice;
+
+use crate::MyVec;
+
+/// A fixed-capacity circular buffer that keeps the last `capacity` pushed
+/// elements, overwriting the oldest one when full.
+pub struct HistoryBuffer<T> {
+    buf: MyVec<T>,
+    capacity: usize,
+    write: usize, // slot the next push goes into
+}
+
+impl<T> HistoryBuffer<T> {
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "capacity must be non-zero");
+
+        Self {
+            buf: MyVec::with_capacity(capacity),
+            capacity,
+            write: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.buf.len() == self.capacity
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Pushes `elem`, returning the element it overwrote once the buffer is
+    /// full.
+    pub fn push(&mut self, elem: T) -> Option<T> {
+        let old = if self.is_full() {
+            Some(mem::replace(&mut self.buf[self.write], elem))
+        } else {
+            self.buf.push(elem);
+            None
+        };
+
+        self.write = (self.write + 1) % self.capacity;
+
+        old
+    }
+
+    /// Returns the most recently pushed element.
+    pub fn latest(&self) -> Option<&T> {
+        if self.is_empty() {
+            None
+        } else if self.write == 0 {
+            self.buf.last()
+        } else {
+            self.buf.get(self.write - 1)
+        }
+    }
+
+    /// Returns the oldest element still retained.
+    pub fn oldest(&self) -> Option<&T> {
+        self.iter_oldest_first().next()
+    }
+
+    pub fn iter_oldest_first(&self) -> Chain<slice::Iter<'_, T>, slice::Iter<'_, T>> {
+        // until the first wrap `write == len`, so the first half is empty
+        self.buf[self.write..]
+            .iter()
+            .chain(self.buf[..self.write].iter())
+    }
+
+    pub fn clear(&mut self) {
+        while self.buf.pop().is_some() {}
+        self.write = 0;
+    }
+}
+
+#[test]
+fn history_overwrites_oldest() {
+    let mut h = HistoryBuffer::new(3);
+    assert_eq!(None, h.latest());
+
+    for i in 0..3 {
+        assert_eq!(None, h.push(i));
+    }
+    assert_eq!(Some(&2), h.latest());
+    assert_eq!(
+        vec![0, 1, 2],
+        h.iter_oldest_first().copied().collect::<Vec<_>>()
+    );
+
+    assert_eq!(Some(0), h.push(3));
+    assert_eq!(Some(1), h.push(4));
+    assert_eq!(Some(&4), h.latest());
+    assert_eq!(Some(&2), h.oldest());
+    assert_eq!(
+        vec![2, 3, 4],
+        h.iter_oldest_first().copied().collect::<Vec<_>>()
+    );
+
+    h.clear();
+    assert!(h.is_empty());
+    h.push(9);
+    assert_eq!(Some(&9), h.latest());
+}
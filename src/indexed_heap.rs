@@ -0,0 +1,187 @@
+use std::mem;
+
+use crate::MyVec;
+
+const NIL: usize = usize::MAX;
+
+/// A binary min-heap over dense `usize` keys that tracks where each key sits,
+/// so priorities can be changed or keys removed in O(log n).
+///
+/// Keys are meant to be node ids from a flat index space, as in Dijkstra or
+/// A*; the position table grows to the largest key pushed.
+pub struct IndexedHeap<P> {
+    heap: MyVec<(usize, P)>,
+    positions: MyVec<usize>,
+}
+
+impl<P: Ord> IndexedHeap<P> {
+    pub fn new() -> Self {
+        Self {
+            heap: MyVec::new(),
+            positions: MyVec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    pub fn contains(&self, key: usize) -> bool {
+        self.position(key).is_some()
+    }
+
+    pub fn priority(&self, key: usize) -> Option<&P> {
+        self.position(key).map(|pos| &self.heap[pos].1)
+    }
+
+    /// Inserts `key` with `priority`, or updates its priority if it is already
+    /// queued, returning the old one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is `usize::MAX`, which the position table reserves as
+    /// its "not queued" marker.
+    pub fn push(&mut self, key: usize, priority: P) -> Option<P> {
+        assert!(key < NIL, "key usize::MAX is reserved");
+
+        if self.contains(key) {
+            return self.change_priority(key, priority);
+        }
+
+        while self.positions.len() <= key {
+            self.positions.push(NIL);
+        }
+
+        self.heap.push((key, priority));
+        self.positions[key] = self.heap.len() - 1;
+        self.sift_up(self.heap.len() - 1);
+
+        None
+    }
+
+    /// Returns the key with the smallest priority.
+    pub fn peek(&self) -> Option<(usize, &P)> {
+        self.heap.first().map(|(key, priority)| (*key, priority))
+    }
+
+    pub fn pop(&mut self) -> Option<(usize, P)> {
+        if self.heap.is_empty() {
+            None
+        } else {
+            Some(self.remove_at(0))
+        }
+    }
+
+    /// Replaces the priority of a queued `key`, returning the old one, or
+    /// `None` if `key` is not queued.
+    pub fn change_priority(&mut self, key: usize, priority: P) -> Option<P> {
+        let pos = self.position(key)?;
+        let old = mem::replace(&mut self.heap[pos].1, priority);
+
+        if self.heap[pos].1 < old {
+            self.sift_up(pos);
+        } else {
+            self.sift_down(pos);
+        }
+
+        Some(old)
+    }
+
+    pub fn remove_by_key(&mut self, key: usize) -> Option<P> {
+        let pos = self.position(key)?;
+        Some(self.remove_at(pos).1)
+    }
+
+    fn position(&self, key: usize) -> Option<usize> {
+        match self.positions.get(key) {
+            Some(&pos) if pos != NIL => Some(pos),
+            _ => None,
+        }
+    }
+
+    fn remove_at(&mut self, pos: usize) -> (usize, P) {
+        let last = self.heap.len() - 1;
+        self.swap(pos, last);
+
+        let removed = self.heap.pop().unwrap();
+        self.positions[removed.0] = NIL;
+
+        if pos < self.heap.len() {
+            self.sift_up(pos);
+            self.sift_down(pos);
+        }
+
+        removed
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.positions[self.heap[a].0] = a;
+        self.positions[self.heap[b].0] = b;
+    }
+
+    fn sift_up(&mut self, mut pos: usize) {
+        while pos > 0 {
+            let parent = (pos - 1) / 2;
+            if self.heap[pos].1 >= self.heap[parent].1 {
+                break;
+            }
+            self.swap(pos, parent);
+            pos = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut pos: usize) {
+        loop {
+            let left = 2 * pos + 1;
+            let right = left + 1;
+            let mut smallest = pos;
+
+            if left < self.heap.len() && self.heap[left].1 < self.heap[smallest].1 {
+                smallest = left;
+            }
+            if right < self.heap.len() && self.heap[right].1 < self.heap[smallest].1 {
+                smallest = right;
+            }
+            if smallest == pos {
+                break;
+            }
+
+            self.swap(pos, smallest);
+            pos = smallest;
+        }
+    }
+}
+
+impl<P: Ord> Default for IndexedHeap<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[test]
+fn indexed_heap_decrease_key() {
+    let mut heap = IndexedHeap::new();
+    heap.push(0, 10);
+    heap.push(1, 5);
+    heap.push(2, 7);
+    heap.push(3, 1);
+
+    assert_eq!(Some((3, &1)), heap.peek());
+    assert_eq!(Some(10), heap.change_priority(0, 0));
+    assert_eq!(Some((0, &0)), heap.peek());
+    assert_eq!(Some(1), heap.remove_by_key(3));
+    assert_eq!(None, heap.remove_by_key(3));
+    assert_eq!(Some(5), heap.push(1, 8));
+
+    let mut order = Vec::new();
+    while let Some((key, priority)) = heap.pop() {
+        order.push((key, priority));
+    }
+    assert_eq!(vec![(0, 0), (2, 7), (1, 8)], order);
+    assert!(!heap.contains(0));
+}
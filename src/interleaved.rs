@@ -0,0 +1,135 @@
+use std::marker::PhantomData;
+use std::mem;
+use std::ptr;
+
+use crate::{MyVec, Pod};
+
+/// A vector of `T` records laid out with an explicit byte `stride`, which may
+/// be larger than `size_of::<T>()`, as graphics APIs expect from vertex
+/// buffers.
+///
+/// The gap after each record is zero-filled, so [`as_bytes`](Self::as_bytes)
+/// can be uploaded as is. Records are not necessarily aligned in memory, so
+/// they and their fields are read and written by value.
+pub struct InterleavedVec<T> {
+    bytes: MyVec<u8>,
+    stride: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Pod> InterleavedVec<T> {
+    /// Creates an empty vector of `T` records spaced `stride` bytes apart.
+    pub fn with_stride(stride: usize) -> Self {
+        assert!(
+            stride >= mem::size_of::<T>() && stride > 0,
+            "stride smaller than the record"
+        );
+
+        Self {
+            bytes: MyVec::new(),
+            stride,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    pub fn len(&self) -> usize {
+        self.bytes.len() / self.stride
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    pub fn push(&mut self, record: T) {
+        let start = self.bytes.len();
+        self.bytes.resize_zeroed(start + self.stride);
+
+        unsafe { ptr::write_unaligned(self.bytes.as_mut_ptr().add(start) as *mut T, record) }
+    }
+
+    pub fn get(&self, idx: usize) -> T {
+        self.field(idx, 0)
+    }
+
+    pub fn set(&mut self, idx: usize, record: T) {
+        self.set_field(idx, 0, record);
+    }
+
+    /// Reads the `F` stored `offset` bytes into record `idx`.
+    pub fn field<F: Pod>(&self, idx: usize, offset: usize) -> F {
+        let at = self.field_start::<F>(idx, offset);
+
+        unsafe { ptr::read_unaligned(self.bytes.as_ptr().add(at) as *const F) }
+    }
+
+    /// Writes `value` `offset` bytes into record `idx`.
+    pub fn set_field<F: Pod>(&mut self, idx: usize, offset: usize, value: F) {
+        let at = self.field_start::<F>(idx, offset);
+
+        unsafe { ptr::write_unaligned(self.bytes.as_mut_ptr().add(at) as *mut F, value) }
+    }
+
+    /// Returns the records, strides included, as raw bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn field_start<F>(&self, idx: usize, offset: usize) -> usize {
+        assert!(idx < self.len(), "index out of bounds");
+        assert!(
+            offset
+                .checked_add(mem::size_of::<F>())
+                .is_some_and(|end| end <= self.stride),
+            "field outside the record"
+        );
+
+        idx * self.stride + offset
+    }
+}
+
+#[cfg(test)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+struct Vertex {
+    pos: [f32; 3],
+    color: u32,
+}
+
+#[cfg(test)]
+unsafe impl crate::Zeroable for Vertex {}
+#[cfg(test)]
+unsafe impl Pod for Vertex {}
+
+#[test]
+fn interleaved_stride_and_fields() {
+    // a 16-byte vertex padded to 20 bytes
+    let mut v = InterleavedVec::<Vertex>::with_stride(20);
+    v.push(Vertex {
+        pos: [1.0, 2.0, 3.0],
+        color: 0xff00ff00,
+    });
+    v.push(Vertex {
+        pos: [4.0, 5.0, 6.0],
+        color: 0x00ff00ff,
+    });
+
+    assert_eq!(2, v.len());
+    assert_eq!(40, v.as_bytes().len());
+    assert_eq!([0; 4], v.as_bytes()[16..20]);
+
+    assert_eq!([4.0, 5.0, 6.0], v.field::<[f32; 3]>(1, 0));
+    assert_eq!(0x00ff00ff, v.field::<u32>(1, 12));
+
+    v.set_field(0, 4, 9.0f32);
+    assert_eq!(
+        Vertex {
+            pos: [1.0, 9.0, 3.0],
+            color: 0xff00ff00
+        },
+        v.get(0)
+    );
+}
@@ -0,0 +1,87 @@
+use std::mem;
+use std::ptr::NonNull;
+
+use crate::{array_layout, MyVec, MyVecIterator};
+
+impl<T> MyVec<T> {
+    /// Panics if the vector's internal bookkeeping is inconsistent: a length
+    /// past the capacity, a misaligned or unexpectedly dangling pointer, or a
+    /// capacity no valid allocation could have.
+    ///
+    /// Meant for code that manipulates the vector through raw pointers. With
+    /// the `debug-invariants` feature, debug builds also run it whenever the
+    /// vector grows.
+    pub fn assert_invariants(&self) {
+        let (ptr, cap) = (self.buf.ptr, self.buf.cap);
+
+        assert!(
+            self.len <= cap,
+            "length {} exceeds capacity {cap}",
+            self.len
+        );
+        assert!(ptr.as_ptr().is_aligned(), "misaligned buffer pointer");
+
+        if mem::size_of::<T>() == 0 {
+            assert_eq!(usize::MAX, cap, "zero-sized elements need no capacity");
+            assert_eq!(
+                NonNull::dangling(),
+                ptr,
+                "zero-sized vectors never allocate"
+            );
+        } else if cap == 0 {
+            assert_eq!(NonNull::dangling(), ptr, "unallocated vectors are dangling");
+        } else {
+            assert!(array_layout::<T>(cap).is_ok(), "capacity {cap} overflows");
+        }
+
+        #[cfg(feature = "alloc-stats")]
+        assert!(
+            self.buf.stats.max_len >= self.len,
+            "high-water length below the current length"
+        );
+    }
+}
+
+impl<T> MyVecIterator<T> {
+    /// Panics if the iterator's cursors are out of order or outside the
+    /// buffer it owns.
+    pub fn assert_invariants(&self) {
+        let (start, end) = (self.iter.start.addr(), self.iter.end.addr());
+        assert!(start <= end, "iterator start is past its end");
+
+        if mem::size_of::<T>() != 0 && self.buf.cap != 0 {
+            let base = self.buf.ptr.as_ptr().addr();
+            let limit = base + self.buf.cap * mem::size_of::<T>();
+            assert!(base <= start && end <= limit, "iterator outside its buffer");
+            assert!(
+                (start - base).is_multiple_of(mem::size_of::<T>()),
+                "iterator cursor between elements"
+            );
+        }
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn invariants_hold_and_catch_breakage() {
+    let mut v = MyVec::new();
+    v.assert_invariants();
+    for i in 0..10u64 {
+        v.push(i);
+        v.assert_invariants();
+    }
+
+    let mut iter = v.into_iter();
+    iter.next();
+    iter.next_back();
+    iter.assert_invariants();
+
+    let mut v = iter.into_myvec();
+    v.assert_invariants();
+    MyVec::<()>::new().assert_invariants();
+
+    v.len = v.capacity() + 1;
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| v.assert_invariants()));
+    v.len = 0;
+    assert!(result.is_err());
+}
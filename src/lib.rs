@@ -1,13 +1,276 @@
 use std::alloc::{self, Layout};
+use std::cmp;
 use std::marker::PhantomData;
 use std::mem;
-use std::ops::{Deref, DerefMut};
+use std::ops::{Deref, DerefMut, Range};
 use std::ptr::{self, NonNull};
 use std::slice;
 
+#[cfg(not(no_global_oom_handling))]
+mod any_vec;
+#[cfg(not(no_global_oom_handling))]
+mod argsort;
+#[cfg(not(no_global_oom_handling))]
+mod bitset;
+#[cfg(not(no_global_oom_handling))]
+mod bloom;
+#[cfg(feature = "memory-budget")]
+mod budget;
+mod bytes;
+mod cell;
+#[cfg(feature = "checksum")]
+mod checksum;
+mod collect;
+#[cfg(not(no_global_oom_handling))]
+mod compressed;
+mod double_buffered;
+#[cfg(not(no_global_oom_handling))]
+mod drain_chunks;
+mod drain_step_by;
+mod elementwise;
+mod endian;
+mod eq;
+mod error;
+#[cfg(not(no_global_oom_handling))]
+mod extend;
+#[cfg(feature = "failing-alloc")]
+pub mod failing_alloc;
+#[cfg(not(no_global_oom_handling))]
+mod fenwick;
+mod filter_map;
+mod find_remove;
+mod float;
+mod gather;
+#[cfg(not(no_global_oom_handling))]
+mod history;
+#[cfg(not(no_global_oom_handling))]
+mod indexed_heap;
+#[cfg(not(no_global_oom_handling))]
+mod interleaved;
+mod invariants;
+#[cfg(not(no_global_oom_handling))]
+mod lru;
+#[cfg(not(no_global_oom_handling))]
+mod merge_sort;
+#[cfg(not(no_global_oom_handling))]
+mod min_max_heap;
+mod no_alloc;
+#[cfg(not(no_global_oom_handling))]
+mod ops;
+#[cfg(not(no_global_oom_handling))]
+mod option_vec;
+#[cfg(not(no_global_oom_handling))]
+mod paletted;
+mod partition;
+#[cfg(not(no_global_oom_handling))]
+mod permute;
+mod pod;
+#[cfg(not(no_global_oom_handling))]
+mod pool;
+mod prefetch;
+#[cfg(not(no_global_oom_handling))]
+mod radix;
+#[cfg(not(no_global_oom_handling))]
+mod range;
+#[cfg(not(no_global_oom_handling))]
+mod read;
+mod regroup;
+mod scan;
+#[cfg(not(no_global_oom_handling))]
+mod segment_tree;
+mod select;
+#[cfg(not(no_global_oom_handling))]
+mod shared;
+#[cfg(any(
+    all(
+        target_os = "linux",
+        any(
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "arm",
+            target_arch = "aarch64",
+            target_arch = "riscv64",
+            target_arch = "loongarch64"
+        )
+    ),
+    all(
+        target_os = "macos",
+        any(target_arch = "x86_64", target_arch = "aarch64")
+    )
+))]
+mod shm_vec;
+mod snapshot;
+mod split;
+#[cfg(feature = "alloc-stats")]
+mod stats;
+mod string;
+// the `sys` constants are only known to be right on these targets
+#[cfg(any(
+    all(
+        target_os = "linux",
+        any(
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "arm",
+            target_arch = "aarch64",
+            target_arch = "riscv64",
+            target_arch = "loongarch64"
+        )
+    ),
+    all(
+        target_os = "macos",
+        any(target_arch = "x86_64", target_arch = "aarch64")
+    )
+))]
+mod sys;
+#[cfg(not(no_global_oom_handling))]
+mod union_find;
+mod varint;
+#[cfg(not(no_global_oom_handling))]
+mod vec_list;
+#[cfg(any(
+    all(
+        target_os = "linux",
+        any(
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "arm",
+            target_arch = "aarch64",
+            target_arch = "riscv64",
+            target_arch = "loongarch64"
+        )
+    ),
+    all(
+        target_os = "macos",
+        any(target_arch = "x86_64", target_arch = "aarch64")
+    )
+))]
+mod virtual_vec;
+mod zeroed;
+
+#[cfg(not(no_global_oom_handling))]
+pub use any_vec::AnyVec;
+#[cfg(not(no_global_oom_handling))]
+pub use bloom::BloomFilter;
+#[cfg(feature = "memory-budget")]
+pub use budget::MemoryBudget;
+#[cfg(feature = "checksum")]
+pub use checksum::{ChecksumMismatch, ChecksummedVec, VerifyPolicy};
+pub use collect::{IteratorExt, TryFromIterator};
+#[cfg(not(no_global_oom_handling))]
+pub use compressed::{BlockCodec, CompressedVec, RunLength};
+pub use double_buffered::DoubleBuffered;
+#[cfg(not(no_global_oom_handling))]
+pub use drain_chunks::DrainChunks;
+pub use drain_step_by::DrainStepBy;
+pub use elementwise::Elementwise;
+pub use endian::ByteSwap;
+pub use error::TryReserveError;
+#[cfg(not(no_global_oom_handling))]
+pub use fenwick::FenwickTree;
+pub use float::Float;
+#[cfg(not(no_global_oom_handling))]
+pub use history::HistoryBuffer;
+#[cfg(not(no_global_oom_handling))]
+pub use indexed_heap::IndexedHeap;
+#[cfg(not(no_global_oom_handling))]
+pub use interleaved::InterleavedVec;
+#[cfg(not(no_global_oom_handling))]
+pub use lru::LruVec;
+#[cfg(not(no_global_oom_handling))]
+pub use min_max_heap::MinMaxHeap;
+pub use no_alloc::{allocation_allowed, no_alloc_scope};
+#[cfg(not(no_global_oom_handling))]
+pub use option_vec::OptionVec;
+#[cfg(not(no_global_oom_handling))]
+pub use paletted::PalettedVec;
+pub use pod::Pod;
+#[cfg(not(no_global_oom_handling))]
+pub use pool::{BufferPool, PooledBuffer};
+#[cfg(not(no_global_oom_handling))]
+pub use radix::RadixKey;
+#[cfg(not(no_global_oom_handling))]
+pub use range::Arange;
+#[cfg(not(no_global_oom_handling))]
+pub use segment_tree::SegmentTree;
+#[cfg(not(no_global_oom_handling))]
+pub use shared::SharedVec;
+#[cfg(any(
+    all(
+        target_os = "linux",
+        any(
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "arm",
+            target_arch = "aarch64",
+            target_arch = "riscv64",
+            target_arch = "loongarch64"
+        )
+    ),
+    all(
+        target_os = "macos",
+        any(target_arch = "x86_64", target_arch = "aarch64")
+    )
+))]
+pub use shm_vec::ShmVec;
+pub use split::{Lines, SplitOn};
+#[cfg(feature = "alloc-stats")]
+pub use stats::AllocStats;
+pub use string::FromUtf8Error;
+#[cfg(any(
+    all(
+        target_os = "linux",
+        any(
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "arm",
+            target_arch = "aarch64",
+            target_arch = "riscv64",
+            target_arch = "loongarch64"
+        )
+    ),
+    all(
+        target_os = "macos",
+        any(target_arch = "x86_64", target_arch = "aarch64")
+    )
+))]
+pub use sys::Advice;
+#[cfg(not(no_global_oom_handling))]
+pub use union_find::UnionFind;
+pub use varint::{get_varint_i64, get_varint_u64, VarintError};
+#[cfg(not(no_global_oom_handling))]
+pub use vec_list::{Handle, VecList, VecListIter};
+#[cfg(any(
+    all(
+        target_os = "linux",
+        any(
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "arm",
+            target_arch = "aarch64",
+            target_arch = "riscv64",
+            target_arch = "loongarch64"
+        )
+    ),
+    all(
+        target_os = "macos",
+        any(target_arch = "x86_64", target_arch = "aarch64")
+    )
+))]
+pub use virtual_vec::VirtualVec;
+pub use zeroed::Zeroable;
+
 struct RawVec<T> {
     ptr: NonNull<T>,
     cap: usize,
+    // bytes at the start of the buffer known to be initialized, including
+    // spare capacity `extend_from_read` zeroed and did not fill; anything
+    // that hands out the spare capacity for arbitrary writes resets it
+    init: usize,
+    #[cfg(feature = "alloc-stats")]
+    stats: AllocStats,
+    #[cfg(feature = "memory-budget")]
+    budget: Option<MemoryBudget>,
 }
 
 impl<T> RawVec<T> {
@@ -21,48 +284,256 @@ impl<T> RawVec<T> {
         Self {
             ptr: NonNull::dangling(),
             cap,
+            init: 0,
+            #[cfg(feature = "alloc-stats")]
+            stats: AllocStats::default(),
+            #[cfg(feature = "memory-budget")]
+            budget: None,
         }
     }
 
-    fn grow(&mut self) {
-        assert!(mem::size_of::<T>() != 0, "capacity overflow");
+    fn try_allocate(cap: usize, zeroed: bool) -> Result<Self, TryReserveError> {
+        if cap == 0 || mem::size_of::<T>() == 0 {
+            return Ok(Self::new());
+        }
 
-        let (new_cap, new_layout) = if self.cap == 0 {
-            (1, Layout::array::<T>(1).unwrap())
-        } else {
-            let new_cap = 2 * self.cap;
+        let layout = array_layout::<T>(cap)?;
+        no_alloc::check()?;
+
+        let ptr = unsafe { raw_alloc(layout, zeroed) };
+
+        Ok(Self {
+            ptr: NonNull::new(ptr as *mut T).ok_or(TryReserveError::AllocError { layout })?,
+            cap,
+            init: 0,
+            #[cfg(feature = "alloc-stats")]
+            stats: AllocStats {
+                max_capacity: cap,
+                ..AllocStats::default()
+            },
+            #[cfg(feature = "memory-budget")]
+            budget: None,
+        })
+    }
+
+    fn try_with_capacity(cap: usize) -> Result<Self, TryReserveError> {
+        Self::try_allocate(cap, false)
+    }
+
+    #[cfg(not(no_global_oom_handling))]
+    fn with_capacity(cap: usize) -> Self {
+        Self::try_with_capacity(cap).unwrap_or_else(|err| err.handle())
+    }
+
+    /// Allocates a buffer whose bytes are all zero, letting the allocator hand
+    /// out pages the OS already zeroed.
+    fn try_with_capacity_zeroed(cap: usize) -> Result<Self, TryReserveError> {
+        Self::try_allocate(cap, true)
+    }
 
-            let new_layout = Layout::array::<T>(new_cap).unwrap();
-            (new_cap, new_layout)
+    /// Gives an unallocated buffer `cap` zeroed slots, keeping its budget
+    /// and stats.
+    #[cfg(not(no_global_oom_handling))]
+    fn try_allocate_zeroed_in_place(&mut self, cap: usize) -> Result<(), TryReserveError> {
+        debug_assert_eq!(0, self.cap);
+
+        #[cfg(feature = "memory-budget")]
+        let bytes = array_layout::<T>(cap)?.size();
+        #[cfg(feature = "memory-budget")]
+        if let Some(budget) = &self.budget {
+            budget.try_debit(bytes)?;
+        }
+
+        let new = match Self::try_with_capacity_zeroed(cap) {
+            Ok(new) => mem::ManuallyDrop::new(new),
+            Err(err) => {
+                #[cfg(feature = "memory-budget")]
+                if let Some(budget) = &self.budget {
+                    budget.credit(bytes);
+                }
+
+                return Err(err);
+            }
         };
 
-        assert!(
-            new_layout.size() <= isize::MAX as usize,
-            "allocation too large"
-        );
+        self.ptr = new.ptr;
+
+        #[cfg(feature = "alloc-stats")]
+        self.stats.record_growth(0, cap, 0);
+
+        self.cap = cap;
+
+        Ok(())
+    }
+
+    /// # Safety
+    ///
+    /// `ptr` must be dangling if `cap == 0`, and otherwise come from the
+    /// global allocator with the layout of `[T; cap]`.
+    unsafe fn from_raw_parts(ptr: NonNull<T>, cap: usize) -> Self {
+        Self {
+            ptr,
+            cap,
+            init: 0,
+            #[cfg(feature = "alloc-stats")]
+            stats: AllocStats {
+                max_capacity: cap,
+                ..AllocStats::default()
+            },
+            #[cfg(feature = "memory-budget")]
+            budget: None,
+        }
+    }
 
-        let new_ptr = if self.cap == 0 {
-            unsafe { alloc::alloc(new_layout) }
+    fn current_layout(&self) -> Option<Layout> {
+        if self.cap == 0 || mem::size_of::<T>() == 0 {
+            None
         } else {
-            let old_layout = Layout::array::<T>(self.cap).unwrap();
-            let old_ptr = self.ptr.as_ptr() as *mut u8;
-            unsafe { alloc::realloc(old_ptr, old_layout, new_layout.size()) }
+            // the layout was already validated when the buffer was allocated
+            unsafe {
+                Some(Layout::from_size_align_unchecked(
+                    mem::size_of::<T>() * self.cap,
+                    mem::align_of::<T>(),
+                ))
+            }
+        }
+    }
+
+    /// Grows the buffer to hold at least `len + additional` elements, at least
+    /// doubling the capacity so repeated pushes stay amortized O(1).
+    fn try_grow_by(&mut self, len: usize, additional: usize) -> Result<(), TryReserveError> {
+        // a zero-sized buffer already has the maximum capacity
+        if mem::size_of::<T>() == 0 {
+            return Err(TryReserveError::CapacityOverflow);
+        }
+
+        let required = len
+            .checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+
+        if required <= self.cap {
+            return Ok(());
+        }
+
+        let new_cap = cmp::max(self.cap.saturating_mul(2), required);
+        let new_layout = array_layout::<T>(new_cap)?;
+        let old_layout = self.current_layout();
+        no_alloc::check()?;
+
+        #[cfg(feature = "memory-budget")]
+        let extra_bytes = new_layout.size() - old_layout.map_or(0, |layout| layout.size());
+        #[cfg(feature = "memory-budget")]
+        if let Some(budget) = &self.budget {
+            budget.try_debit(extra_bytes)?;
+        }
+
+        let new_ptr = match old_layout {
+            None => unsafe { raw_alloc(new_layout, false) },
+            Some(old_layout) => {
+                let old_ptr = self.ptr.as_ptr() as *mut u8;
+                unsafe { raw_realloc(old_ptr, old_layout, new_layout.size()) }
+            }
         };
 
-        self.ptr = NonNull::new(new_ptr as *mut T)
-            .unwrap_or_else(|| alloc::handle_alloc_error(new_layout));
+        let Some(new_ptr) = NonNull::new(new_ptr as *mut T) else {
+            #[cfg(feature = "memory-budget")]
+            if let Some(budget) = &self.budget {
+                budget.credit(extra_bytes);
+            }
+
+            return Err(TryReserveError::AllocError { layout: new_layout });
+        };
+
+        self.ptr = new_ptr;
+
+        #[cfg(feature = "alloc-stats")]
+        self.stats
+            .record_growth(self.cap, new_cap, len * mem::size_of::<T>());
 
         self.cap = new_cap;
+
+        Ok(())
+    }
+
+    #[cfg(not(no_global_oom_handling))]
+    fn grow_by(&mut self, len: usize, additional: usize) {
+        self.try_grow_by(len, additional)
+            .unwrap_or_else(|err| err.handle())
+    }
+
+    fn try_grow(&mut self) -> Result<(), TryReserveError> {
+        self.try_grow_by(self.cap, 1)
+    }
+
+    #[cfg(not(no_global_oom_handling))]
+    fn grow(&mut self) {
+        self.try_grow().unwrap_or_else(|err| err.handle())
+    }
+
+    /// Reinterprets the buffer as one holding `cap` elements of `U`.
+    ///
+    /// The caller must make sure this describes the same allocation:
+    /// `U` has the alignment of `T` and `cap * size_of::<U>()` equals
+    /// `self.cap * size_of::<T>()`, with `cap` being `usize::MAX` for a
+    /// zero-sized `U`.
+    unsafe fn cast<U>(self, cap: usize) -> RawVec<U> {
+        let this = mem::ManuallyDrop::new(self);
+
+        RawVec {
+            ptr: this.ptr.cast(),
+            cap,
+            init: 0,
+            #[cfg(feature = "alloc-stats")]
+            stats: this.stats,
+            #[cfg(feature = "memory-budget")]
+            budget: ptr::read(&this.budget),
+        }
+    }
+}
+
+// Every allocation RawVec makes goes through these two, so test hooks can
+// intercept them.
+unsafe fn raw_alloc(layout: Layout, zeroed: bool) -> *mut u8 {
+    #[cfg(feature = "failing-alloc")]
+    if failing_alloc::should_fail() {
+        return ptr::null_mut();
+    }
+
+    if zeroed {
+        alloc::alloc_zeroed(layout)
+    } else {
+        alloc::alloc(layout)
+    }
+}
+
+unsafe fn raw_realloc(ptr: *mut u8, old_layout: Layout, new_size: usize) -> *mut u8 {
+    #[cfg(feature = "failing-alloc")]
+    if failing_alloc::should_fail() {
+        return ptr::null_mut();
+    }
+
+    alloc::realloc(ptr, old_layout, new_size)
+}
+
+fn array_layout<T>(cap: usize) -> Result<Layout, TryReserveError> {
+    let layout = Layout::array::<T>(cap).map_err(|_| TryReserveError::CapacityOverflow)?;
+
+    if layout.size() > isize::MAX as usize {
+        return Err(TryReserveError::CapacityOverflow);
     }
+
+    Ok(layout)
 }
 
 impl<T> Drop for RawVec<T> {
     fn drop(&mut self) {
-        let elem_size = mem::size_of::<T>();
-
-        if self.cap != 0 && elem_size != 0 {
+        if let Some(layout) = self.current_layout() {
             let ptr = self.ptr.as_ptr() as *mut u8;
-            let layout = Layout::array::<T>(self.cap).unwrap();
+
+            #[cfg(feature = "memory-budget")]
+            if let Some(budget) = &self.budget {
+                budget.credit(layout.size());
+            }
 
             unsafe { alloc::dealloc(ptr, layout) }
         }
@@ -82,12 +553,129 @@ impl<T> MyVec<T> {
         }
     }
 
+    #[cfg(not(no_global_oom_handling))]
+    pub fn with_capacity(cap: usize) -> Self {
+        MyVec {
+            buf: RawVec::with_capacity(cap),
+            len: 0,
+        }
+    }
+
+    pub fn try_with_capacity(cap: usize) -> Result<Self, TryReserveError> {
+        Ok(MyVec {
+            buf: RawVec::try_with_capacity(cap)?,
+            len: 0,
+        })
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.cap()
+    }
+
+    /// Returns a raw pointer to the vector's buffer.
+    ///
+    /// The pointer is only guaranteed to stay valid while the vector is not
+    /// mutated in a way that may reallocate: a push, insert or reserve that
+    /// goes past the current capacity can move the buffer. Mutations that
+    /// stay within the capacity (pop, remove, writes through `DerefMut`,
+    /// pushes after a large enough `reserve`) never move it.
+    ///
+    /// For an empty vector this is a dangling, well-aligned pointer.
+    pub fn as_ptr(&self) -> *const T {
+        self.ptr()
+    }
+
+    /// Returns a raw mutable pointer to the vector's buffer, with the same
+    /// stability guarantees as [`as_ptr`](Self::as_ptr).
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        // the caller may write anything into the spare capacity
+        self.buf.init = 0;
+        self.ptr()
+    }
+
+    /// Returns the half-open range of pointers spanning the initialized
+    /// elements, with the same stability guarantees as
+    /// [`as_ptr`](Self::as_ptr). For zero-sized `T` both ends are equal.
+    pub fn as_ptr_range(&self) -> Range<*const T> {
+        let start = self.as_ptr();
+
+        start..start.wrapping_add(self.len)
+    }
+
+    /// Mutable version of [`as_ptr_range`](Self::as_ptr_range).
+    pub fn as_mut_ptr_range(&mut self) -> Range<*mut T> {
+        let start = self.as_mut_ptr();
+
+        start..start.wrapping_add(self.len)
+    }
+
+    #[cfg(not(no_global_oom_handling))]
+    pub fn reserve(&mut self, additional: usize) {
+        if self.cap() - self.len < additional {
+            self.buf.grow_by(self.len, additional);
+
+            #[cfg(all(feature = "debug-invariants", debug_assertions))]
+            self.assert_invariants();
+        }
+    }
+
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        if self.cap() - self.len < additional {
+            self.buf.try_grow_by(self.len, additional)?;
+
+            #[cfg(all(feature = "debug-invariants", debug_assertions))]
+            self.assert_invariants();
+        }
+
+        Ok(())
+    }
+
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Drops the elements past `len`, keeping the capacity.
+    pub fn truncate(&mut self, len: usize) {
+        while self.len > len {
+            self.pop();
+        }
+    }
+
+    /// Resizes to `new_len`, filling new slots with `f(i)` for each new
+    /// index `i` in order, after a single reservation.
+    #[cfg(not(no_global_oom_handling))]
+    pub fn resize_with_index(&mut self, new_len: usize, mut f: impl FnMut(usize) -> T) {
+        if new_len <= self.len {
+            self.truncate(new_len);
+            return;
+        }
+
+        self.reserve(new_len - self.len);
+
+        while self.len < new_len {
+            // `len` is bumped after each write, so a panic in `f` keeps
+            // everything created so far
+            unsafe { ptr::write(self.ptr().add(self.len), f(self.len)) };
+            self.len += 1;
+        }
+        self.record_len();
+    }
+
+    #[cfg(not(no_global_oom_handling))]
     fn grow(&mut self) {
         if self.len == self.cap() {
             self.buf.grow();
         }
     }
 
+    fn try_grow(&mut self) -> Result<(), TryReserveError> {
+        if self.len == self.cap() {
+            self.buf.try_grow()?;
+        }
+
+        Ok(())
+    }
+
     fn ptr(&self) -> *mut T {
         self.buf.ptr.as_ptr()
     }
@@ -96,6 +684,21 @@ impl<T> MyVec<T> {
         self.buf.cap
     }
 
+    /// Must be called wherever `len` grows, to keep the high-water mark
+    /// tracked by the `alloc-stats` feature accurate. This is also where the
+    /// `debug-invariants` feature checks the vector.
+    #[inline]
+    fn record_len(&mut self) {
+        #[cfg(feature = "alloc-stats")]
+        if self.len > self.buf.stats.max_len {
+            self.buf.stats.max_len = self.len;
+        }
+
+        #[cfg(all(feature = "debug-invariants", debug_assertions))]
+        self.assert_invariants();
+    }
+
+    #[cfg(not(no_global_oom_handling))]
     pub fn push(&mut self, elem: T) {
         self.grow();
 
@@ -104,6 +707,20 @@ impl<T> MyVec<T> {
         }
 
         self.len += 1;
+        self.record_len();
+    }
+
+    pub fn try_push(&mut self, elem: T) -> Result<(), TryReserveError> {
+        self.try_grow()?;
+
+        unsafe {
+            ptr::write(self.ptr().add(self.len), elem);
+        }
+
+        self.len += 1;
+        self.record_len();
+
+        Ok(())
     }
 
     pub fn pop(&mut self) -> Option<T> {
@@ -115,6 +732,32 @@ impl<T> MyVec<T> {
         }
     }
 
+    /// Moves every element of `other` to the end of `self`, leaving `other`
+    /// empty, with a single reserve and copy.
+    #[cfg(not(no_global_oom_handling))]
+    pub fn append(&mut self, other: &mut Self) {
+        let count = other.len;
+        self.reserve(count);
+
+        unsafe { ptr::copy_nonoverlapping(other.ptr(), self.ptr().add(self.len), count) }
+
+        other.len = 0;
+        self.len += count;
+        self.record_len();
+    }
+
+    /// Pops the last `N` elements as an array, in their original order, or
+    /// returns `None` if fewer than `N` remain.
+    pub fn pop_array<const N: usize>(&mut self) -> Option<[T; N]> {
+        if self.len < N {
+            None
+        } else {
+            self.len -= N;
+            unsafe { Some(ptr::read(self.ptr().add(self.len) as *const [T; N])) }
+        }
+    }
+
+    #[cfg(not(no_global_oom_handling))]
     pub fn insert(&mut self, idx: usize, elem: T) {
         assert!(idx <= self.len, "index out of bounds");
 
@@ -127,6 +770,24 @@ impl<T> MyVec<T> {
         }
 
         self.len += 1;
+        self.record_len();
+    }
+
+    pub fn try_insert(&mut self, idx: usize, elem: T) -> Result<(), TryReserveError> {
+        assert!(idx <= self.len, "index out of bounds");
+
+        self.try_grow()?;
+
+        unsafe {
+            ptr::copy(self.ptr().add(idx), self.ptr().add(idx + 1), self.len - idx);
+
+            ptr::write(self.ptr().add(idx), elem);
+        }
+
+        self.len += 1;
+        self.record_len();
+
+        Ok(())
     }
 
     pub fn remove(&mut self, idx: usize) -> T {
@@ -142,11 +803,93 @@ impl<T> MyVec<T> {
             elem
         }
     }
+
+    /// Removes the element at `idx` in O(1) by moving the last element into
+    /// its place.
+    pub fn swap_remove(&mut self, idx: usize) -> T {
+        assert!(idx < self.len, "index out of bounds");
+
+        self.len -= 1;
+
+        unsafe {
+            let elem = ptr::read(self.ptr().add(idx));
+
+            ptr::copy(self.ptr().add(self.len), self.ptr().add(idx), 1);
+
+            elem
+        }
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+impl<T: Clone> MyVec<T> {
+    /// Creates a vector of `n` clones of `elem`.
+    ///
+    /// For zero-filled vectors of [`Zeroable`] types prefer
+    /// [`zeroed`](MyVec::zeroed), which skips writing the zeros; for [`Pod`]
+    /// types, [`from_elem_pod`](MyVec::from_elem_pod) picks that path
+    /// whenever `elem` is all zero bytes.
+    pub fn from_elem(elem: T, n: usize) -> Self {
+        let mut v = Self::with_capacity(n);
+        v.resize(n, elem);
+        v
+    }
+
+    /// Clones and appends every element of `other`, with a single reserve.
+    pub fn extend_from_slice(&mut self, other: &[T]) {
+        self.reserve(other.len());
+
+        for elem in other {
+            self.push(elem.clone());
+        }
+    }
+
+    /// Resizes the vector to `new_len`, filling new slots with clones of
+    /// `value` or dropping the excess elements.
+    pub fn resize(&mut self, new_len: usize, value: T) {
+        if new_len <= self.len {
+            self.truncate(new_len);
+            return;
+        }
+
+        self.reserve(new_len - self.len);
+
+        for _ in self.len + 1..new_len {
+            self.push(value.clone());
+        }
+        self.push(value);
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+impl<T: Clone> Clone for MyVec<T> {
+    fn clone(&self) -> Self {
+        let mut v = Self::with_capacity(self.len);
+        v.extend_from_slice(self);
+        v
+    }
+
+    /// Clones `source` into the existing buffer: overlapping elements are
+    /// updated with `T::clone_from`, and the buffer only grows if `source`
+    /// is longer than its capacity.
+    fn clone_from(&mut self, source: &Self) {
+        self.truncate(source.len);
+
+        let (head, tail) = source.split_at(self.len);
+        self.clone_from_slice(head);
+        self.extend_from_slice(tail);
+    }
+}
+
+impl<T> Default for MyVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<T> Drop for MyVec<T> {
     fn drop(&mut self) {
-        while let Some(_) = self.pop() {}
+        while self.pop().is_some() {}
     }
 }
 
@@ -159,9 +902,12 @@ impl<T> RawValIter<T> {
     unsafe fn new(slice: &[T]) -> Self {
         Self {
             start: slice.as_ptr(),
+            // for zero-sized elements the address itself is the counter; the
+            // pointer is never dereferenced, so it keeps the slice's
+            // provenance instead of being conjured from an integer
             end: if mem::size_of::<T>() == 0 {
-                ((slice.as_ptr() as usize) + slice.len()) as *const _
-            } else if slice.len() == 0 {
+                slice.as_ptr().wrapping_byte_add(slice.len())
+            } else if slice.is_empty() {
                 slice.as_ptr()
             } else {
                 slice.as_ptr().add(slice.len())
@@ -179,7 +925,7 @@ impl<T> Iterator for RawValIter<T> {
         } else {
             unsafe {
                 if mem::size_of::<T>() == 0 {
-                    self.start = (self.start as usize + 1) as *const _;
+                    self.start = self.start.wrapping_byte_add(1);
                     Some(ptr::read(NonNull::<T>::dangling().as_ptr()))
                 } else {
                     let old_ptr = self.start;
@@ -193,7 +939,7 @@ impl<T> Iterator for RawValIter<T> {
     fn size_hint(&self) -> (usize, Option<usize>) {
         let elem_size = mem::size_of::<T>();
         let len =
-            (self.end as usize - self.start as usize) / if elem_size == 0 { 1 } else { elem_size };
+            (self.end.addr() - self.start.addr()) / if elem_size == 0 { 1 } else { elem_size };
         (len, Some(len))
     }
 }
@@ -205,7 +951,7 @@ impl<T> DoubleEndedIterator for RawValIter<T> {
         } else {
             unsafe {
                 if mem::size_of::<T>() == 0 {
-                    self.end = (self.end as usize - 1) as *const _;
+                    self.end = self.end.wrapping_byte_sub(1);
                     Some(ptr::read(NonNull::<T>::dangling().as_ptr()))
                 } else {
                     self.end = self.end.offset(-1);
@@ -217,7 +963,7 @@ impl<T> DoubleEndedIterator for RawValIter<T> {
 }
 
 pub struct MyVecIterator<T> {
-    _buf: RawVec<T>, // just to own and drop
+    buf: RawVec<T>, // owns the allocation the remaining elements live in
     iter: RawValIter<T>,
 }
 
@@ -230,7 +976,26 @@ impl<T> IntoIterator for MyVec<T> {
 
         mem::forget(self);
 
-        MyVecIterator { iter, _buf: buf }
+        MyVecIterator { iter, buf }
+    }
+}
+
+impl<T> MyVecIterator<T> {
+    /// Turns the elements not yet yielded back into a vector, reusing the
+    /// original allocation by shifting them to the front of it.
+    pub fn into_myvec(self) -> MyVec<T> {
+        let len = self.iter.size_hint().0;
+        let this = mem::ManuallyDrop::new(self);
+        let buf = unsafe { ptr::read(&this.buf) };
+
+        #[cfg(all(feature = "debug-invariants", debug_assertions))]
+        this.assert_invariants();
+
+        if mem::size_of::<T>() != 0 {
+            unsafe { ptr::copy(this.iter.start, buf.ptr.as_ptr(), len) }
+        }
+
+        MyVec { buf, len }
     }
 }
 
@@ -302,8 +1067,8 @@ impl<'a, T> Drop for MyDrain<'a, T> {
 }
 
 impl<T> MyVec<T> {
-    pub fn drain(&mut self) -> MyDrain<T> {
-        let iter = unsafe { RawValIter::new(&self) };
+    pub fn drain(&mut self) -> MyDrain<'_, T> {
+        let iter = unsafe { RawValIter::new(self) };
 
         self.len = 0;
 
@@ -317,6 +1082,7 @@ impl<T> MyVec<T> {
 unsafe impl<T: Send> Send for MyVec<T> {}
 unsafe impl<T: Sync> Sync for MyVec<T> {}
 
+#[cfg(not(no_global_oom_handling))]
 #[test]
 fn create_push_pop() {
     let mut v = MyVec::new();
@@ -336,6 +1102,23 @@ fn create_push_pop() {
     assert_eq!(1, v.len());
 }
 
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn test_pop_array() {
+    let mut v = MyVec::new();
+    for i in 0..5 {
+        v.push(Box::new(i));
+    }
+    let [a, b] = v.pop_array().unwrap();
+    assert_eq!((3, 4), (*a, *b));
+    assert!(v.pop_array::<4>().is_none());
+    assert_eq!(3, v.len());
+    let [x, y, z] = v.pop_array().unwrap();
+    assert_eq!((0, 1, 2), (*x, *y, *z));
+    assert_eq!(Some([]), v.pop_array::<0>());
+}
+
+#[cfg(not(no_global_oom_handling))]
 #[test]
 fn iter_test() {
     let mut v = MyVec::new();
@@ -350,6 +1133,56 @@ fn iter_test() {
     assert_eq!(9, *last);
 }
 
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn iter_into_myvec() {
+    let mut v = MyVec::new();
+    for i in 0..10 {
+        v.push(Box::new(i))
+    }
+    let ptr = v.as_ptr();
+    let mut iter = v.into_iter();
+    iter.next();
+    iter.next();
+    iter.next_back();
+    let rest = iter.into_myvec();
+    assert_eq!(ptr, rest.as_ptr());
+    assert_eq!(16, rest.capacity());
+    assert_eq!(
+        vec![2, 3, 4, 5, 6, 7, 8],
+        rest.iter().map(|b| **b).collect::<Vec<_>>()
+    );
+
+    let mut zst = MyVec::new();
+    for _ in 0..5 {
+        zst.push(());
+    }
+    let mut iter = zst.into_iter();
+    iter.next();
+    assert_eq!(4, iter.into_myvec().len());
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn ptr_range() {
+    let mut v = MyVec::with_capacity(4);
+    assert_eq!(v.as_ptr_range().start, v.as_ptr_range().end);
+
+    v.push(1u32);
+    v.push(2);
+    let range = v.as_ptr_range();
+    assert_eq!(2, unsafe { range.end.offset_from(range.start) });
+
+    // pushes within capacity keep the buffer in place
+    v.push(3);
+    assert_eq!(range.start, v.as_ptr());
+
+    let range = v.as_mut_ptr_range();
+    unsafe { *range.end.sub(1) = 4 };
+    assert_eq!(&[1, 2, 4], &v[..]);
+}
+
+#[cfg(not(no_global_oom_handling))]
 #[test]
 fn test_drain() {
     let mut v = MyVec::new();
@@ -368,6 +1201,47 @@ fn test_drain() {
     assert_eq!(1, *v.pop().unwrap());
 }
 
+#[test]
+fn test_try_reserve() {
+    let mut v: MyVec<u64> = MyVec::try_with_capacity(2).unwrap();
+    v.try_push(1).unwrap();
+    v.try_insert(0, 0).unwrap();
+    v.try_reserve(10).unwrap();
+    assert!(v.capacity() >= 12);
+    assert_eq!(&[0, 1], &v[..]);
+
+    assert_eq!(
+        Err(TryReserveError::CapacityOverflow),
+        v.try_reserve(usize::MAX)
+    );
+    assert!(MyVec::<u64>::try_with_capacity(usize::MAX / 4).is_err());
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn test_reserve_grows_once() {
+    let mut v = MyVec::new();
+    v.push(0u32);
+    v.reserve(1000);
+    assert_eq!(1001, v.capacity());
+
+    // small requests still double
+    for i in 1..1001 {
+        v.push(i);
+    }
+    v.reserve(1);
+    assert_eq!(2002, v.capacity());
+
+    let mut zst = MyVec::new();
+    zst.push(());
+    assert!(zst.try_reserve(10).is_ok());
+    assert_eq!(
+        Err(TryReserveError::CapacityOverflow),
+        zst.try_reserve(usize::MAX)
+    );
+}
+
+#[cfg(not(no_global_oom_handling))]
 #[test]
 fn test_zst() {
     let mut v = MyVec::new();
@@ -383,3 +1257,44 @@ fn test_zst() {
 
     assert_eq!(10, count);
 }
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn test_resize_with_index() {
+    let mut v = MyVec::new();
+    v.push(100);
+    v.resize_with_index(5, |i| i * i);
+    assert_eq!([100, 1, 4, 9, 16], *v);
+    assert_eq!(5, v.capacity());
+
+    v.resize_with_index(2, |_| unreachable!());
+    assert_eq!([100, 1], *v);
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn test_clone_from_reuses_buffer() {
+    let mut source = MyVec::new();
+    for i in 0..8 {
+        source.push(i.to_string());
+    }
+
+    let copy = source.clone();
+    assert_eq!(*source, *copy);
+    assert_eq!(8, copy.capacity());
+
+    let mut dest = MyVec::with_capacity(16);
+    dest.push(String::with_capacity(64));
+    let (buf, first) = (dest.as_ptr(), dest[0].as_ptr());
+
+    dest.clone_from(&source);
+    assert_eq!(*source, *dest);
+    assert_eq!(buf, dest.as_ptr());
+    // the existing string's allocation is reused too
+    assert_eq!(first, dest[0].as_ptr());
+
+    source.truncate(3);
+    dest.clone_from(&source);
+    assert_eq!(["0", "1", "2"], *dest);
+    assert_eq!(buf, dest.as_ptr());
+}
@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::mem;
+
+use crate::MyVec;
+
+const NIL: usize = usize::MAX;
+
+/// A fixed-capacity least-recently-used cache.
+///
+/// Entries live in parallel `MyVec`s and the recency list links them by slot
+/// index, so once the cache is full an insertion reuses the evicted slot
+/// instead of allocating.
+pub struct LruVec<K, V> {
+    keys: MyVec<K>,
+    values: MyVec<V>,
+    prev: MyVec<usize>,
+    next: MyVec<usize>,
+    index: HashMap<K, usize>,
+    head: usize, // most recently used
+    tail: usize, // least recently used
+    capacity: usize,
+}
+
+impl<K: Hash + Eq + Clone, V> LruVec<K, V> {
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "capacity must be non-zero");
+
+        Self {
+            keys: MyVec::with_capacity(capacity),
+            values: MyVec::with_capacity(capacity),
+            prev: MyVec::with_capacity(capacity),
+            next: MyVec::with_capacity(capacity),
+            index: HashMap::with_capacity(capacity),
+            head: NIL,
+            tail: NIL,
+            capacity,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.index.contains_key(key)
+    }
+
+    /// Returns the value for `key` and marks it as the most recently used.
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let slot = *self.index.get(key)?;
+        self.touch(slot);
+        Some(&self.values[slot])
+    }
+
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let slot = *self.index.get(key)?;
+        self.touch(slot);
+        Some(&mut self.values[slot])
+    }
+
+    /// Returns the value for `key` without updating its recency.
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        self.index.get(key).map(|&slot| &self.values[slot])
+    }
+
+    /// Inserts `value` for `key` as the most recently used entry, evicting the
+    /// least recently used one if the cache is full. Returns the previous
+    /// value for `key`, if any.
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(&slot) = self.index.get(&key) {
+            self.touch(slot);
+            return Some(mem::replace(&mut self.values[slot], value));
+        }
+
+        let slot = if self.len() < self.capacity {
+            self.keys.push(key.clone());
+            self.values.push(value);
+            self.prev.push(NIL);
+            self.next.push(NIL);
+            self.keys.len() - 1
+        } else {
+            let slot = self.tail;
+            self.unlink(slot);
+            let old_key = mem::replace(&mut self.keys[slot], key.clone());
+            self.index.remove(&old_key);
+            self.values[slot] = value;
+            slot
+        };
+
+        self.index.insert(key, slot);
+        self.push_front(slot);
+
+        None
+    }
+
+    /// Iterates over the entries from most to least recently used.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        let mut slot = self.head;
+
+        std::iter::from_fn(move || {
+            if slot == NIL {
+                None
+            } else {
+                let current = slot;
+                slot = self.next[current];
+                Some((&self.keys[current], &self.values[current]))
+            }
+        })
+    }
+
+    fn touch(&mut self, slot: usize) {
+        if self.head != slot {
+            self.unlink(slot);
+            self.push_front(slot);
+        }
+    }
+
+    fn unlink(&mut self, slot: usize) {
+        let (prev, next) = (self.prev[slot], self.next[slot]);
+
+        if prev == NIL {
+            self.head = next;
+        } else {
+            self.next[prev] = next;
+        }
+
+        if next == NIL {
+            self.tail = prev;
+        } else {
+            self.prev[next] = prev;
+        }
+    }
+
+    fn push_front(&mut self, slot: usize) {
+        self.prev[slot] = NIL;
+        self.next[slot] = self.head;
+
+        if self.head == NIL {
+            self.tail = slot;
+        } else {
+            self.prev[self.head] = slot;
+        }
+
+        self.head = slot;
+    }
+}
+
+#[test]
+fn lru_evicts_least_recent() {
+    let mut lru = LruVec::new(2);
+    assert_eq!(None, lru.put("a", 1));
+    assert_eq!(None, lru.put("b", 2));
+    assert_eq!(Some(&1), lru.get(&"a"));
+
+    lru.put("c", 3);
+    assert!(!lru.contains(&"b"));
+    assert_eq!(2, lru.len());
+
+    assert_eq!(Some(3), lru.put("c", 30));
+    let order: Vec<_> = lru.iter().map(|(k, v)| (*k, *v)).collect();
+    assert_eq!(vec![("c", 30), ("a", 1)], order);
+
+    lru.put("d", 4);
+    assert_eq!(None, lru.peek(&"a"));
+    assert_eq!(Some(&30), lru.peek(&"c"));
+}
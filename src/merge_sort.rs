@@ -0,0 +1,170 @@
+use std::cmp::Ordering;
+use std::mem;
+use std::ptr;
+
+use crate::MyVec;
+
+/// Runs this short are insertion sorted instead of split further.
+const INSERTION_LEN: usize = 20;
+
+impl<T> MyVec<T> {
+    /// Sorts the vector stably, like `slice::sort`, but borrows its temporary
+    /// buffer from `scratch` instead of allocating one.
+    ///
+    /// `scratch` is cleared, and only grows if its capacity is below half of
+    /// this vector's length, so reusing one scratch vector across many sorts
+    /// allocates at most a handful of times.
+    pub fn sort_stable_with_scratch(&mut self, scratch: &mut MyVec<T>)
+    where
+        T: Ord,
+    {
+        self.sort_stable_by_with_scratch(scratch, T::cmp);
+    }
+
+    pub fn sort_stable_by_key_with_scratch<K: Ord>(
+        &mut self,
+        scratch: &mut MyVec<T>,
+        mut f: impl FnMut(&T) -> K,
+    ) {
+        self.sort_stable_by_with_scratch(scratch, |a, b| f(a).cmp(&f(b)));
+    }
+
+    pub fn sort_stable_by_with_scratch(
+        &mut self,
+        scratch: &mut MyVec<T>,
+        mut cmp: impl FnMut(&T, &T) -> Ordering,
+    ) {
+        if self.len < 2 || mem::size_of::<T>() == 0 {
+            return;
+        }
+
+        scratch.clear();
+        if self.len > INSERTION_LEN {
+            scratch.reserve(self.len / 2);
+        }
+
+        let mut is_less = |a: &T, b: &T| cmp(a, b) == Ordering::Less;
+        unsafe { merge_sort(self, scratch.ptr(), &mut is_less) };
+    }
+}
+
+/// # Safety
+///
+/// `buf` must have room for `v.len() / 2` elements.
+unsafe fn merge_sort<T>(v: &mut [T], buf: *mut T, is_less: &mut impl FnMut(&T, &T) -> bool) {
+    let len = v.len();
+
+    if len <= INSERTION_LEN {
+        insertion_sort(v, is_less);
+        return;
+    }
+
+    let mid = len / 2;
+    merge_sort(&mut v[..mid], buf, is_less);
+    merge_sort(&mut v[mid..], buf, is_less);
+
+    if is_less(&v[mid], &v[mid - 1]) {
+        merge(v, mid, buf, is_less);
+    }
+}
+
+fn insertion_sort<T>(v: &mut [T], is_less: &mut impl FnMut(&T, &T) -> bool) {
+    for i in 1..v.len() {
+        // after every equal element, keeping the sort stable
+        let pos = v[..i].partition_point(|x| !is_less(&v[i], x));
+        v[pos..=i].rotate_right(1);
+    }
+}
+
+/// Merges the sorted runs `v[..mid]` and `v[mid..]`, moving the left one
+/// into `buf` first.
+unsafe fn merge<T>(v: &mut [T], mid: usize, buf: *mut T, is_less: &mut impl FnMut(&T, &T) -> bool) {
+    let v = v.as_mut_ptr_range();
+    let right_start = v.start.add(mid);
+    ptr::copy_nonoverlapping(v.start, buf, mid);
+
+    // if `is_less` panics, the hole's drop moves the rest of the left run
+    // back, so every element is in `v` exactly once
+    let mut hole = MergeHole {
+        start: buf,
+        end: buf.add(mid),
+        dest: v.start,
+    };
+    let mut right = right_start;
+
+    while hole.start < hole.end && right < v.end {
+        let take_right = is_less(&*right, &*hole.start);
+        let src = if take_right { right } else { hole.start };
+
+        ptr::copy_nonoverlapping(src, hole.dest, 1);
+        hole.dest = hole.dest.add(1);
+        if take_right {
+            right = right.add(1);
+        } else {
+            hole.start = hole.start.add(1);
+        }
+    }
+}
+
+struct MergeHole<T> {
+    start: *mut T,
+    end: *mut T,
+    dest: *mut T,
+}
+
+impl<T> Drop for MergeHole<T> {
+    fn drop(&mut self) {
+        unsafe {
+            let len = self.end.offset_from(self.start) as usize;
+            ptr::copy_nonoverlapping(self.start, self.dest, len);
+        }
+    }
+}
+
+#[test]
+fn scratch_sort_is_stable_and_reuses_buffer() {
+    let mut scratch = MyVec::new();
+
+    let mut v = MyVec::new();
+    for i in 0..500u32 {
+        v.push((i * 7919 % 61, i));
+    }
+    v.sort_stable_by_key_with_scratch(&mut scratch, |&(k, _)| k);
+    assert!(v.windows(2).all(|w| w[0] < w[1]));
+
+    let cap = scratch.capacity();
+    let ptr = scratch.as_ptr();
+    v.sort_stable_with_scratch(&mut scratch);
+    assert_eq!((cap, ptr), (scratch.capacity(), scratch.as_ptr()));
+
+    // a comparison panicking during the final merge leaves every element
+    // in place exactly once
+    let reversed = || (0..100).rev().map(Box::new).collect::<Vec<_>>();
+    let mut box_scratch = MyVec::new();
+    let mut total = 0;
+    let mut boxes = MyVec::new();
+    for b in reversed() {
+        boxes.push(b);
+    }
+    boxes.sort_stable_by_with_scratch(&mut box_scratch, |a, b| {
+        total += 1;
+        a.cmp(b)
+    });
+
+    let mut boxes = MyVec::new();
+    for b in reversed() {
+        boxes.push(b);
+    }
+    let mut calls = 0;
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        boxes.sort_stable_by_with_scratch(&mut box_scratch, |a, b| {
+            calls += 1;
+            assert!(calls < total - 5);
+            a.cmp(b)
+        });
+    }));
+    assert!(result.is_err());
+    let mut seen: Vec<_> = boxes.iter().map(|b| **b).collect();
+    seen.sort();
+    assert_eq!((0..100).collect::<Vec<_>>(), seen);
+}
@@ -0,0 +1,182 @@
+use crate::MyVec;
+
+/// A double-ended priority queue: O(log n) push, and O(log n) removal of
+/// either the smallest or the largest element.
+///
+/// Laid out as a binary heap whose even levels are ordered like a min-heap
+/// and odd levels like a max-heap, so the minimum is the root and the
+/// maximum one of its two children.
+pub struct MinMaxHeap<T> {
+    heap: MyVec<T>,
+}
+
+impl<T: Ord> MinMaxHeap<T> {
+    pub fn new() -> Self {
+        Self { heap: MyVec::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.heap.clear();
+    }
+
+    pub fn push(&mut self, elem: T) {
+        self.heap.push(elem);
+        self.bubble_up(self.heap.len() - 1);
+    }
+
+    pub fn peek_min(&self) -> Option<&T> {
+        self.heap.first()
+    }
+
+    pub fn peek_max(&self) -> Option<&T> {
+        self.max_index().map(|i| &self.heap[i])
+    }
+
+    pub fn pop_min(&mut self) -> Option<T> {
+        self.remove(0)
+    }
+
+    pub fn pop_max(&mut self) -> Option<T> {
+        self.remove(self.max_index()?)
+    }
+
+    fn max_index(&self) -> Option<usize> {
+        match self.heap.len() {
+            0 => None,
+            1 => Some(0),
+            2 => Some(1),
+            _ => Some(if self.heap[1] >= self.heap[2] { 1 } else { 2 }),
+        }
+    }
+
+    fn remove(&mut self, idx: usize) -> Option<T> {
+        let last = self.heap.len().checked_sub(1)?;
+        self.heap.swap(idx, last);
+        let elem = self.heap.pop();
+
+        if idx < self.heap.len() {
+            self.trickle_down(idx);
+        }
+
+        elem
+    }
+
+    /// Whether `a` belongs above `b` on a level of the given kind.
+    fn above(&self, a: usize, b: usize, min_level: bool) -> bool {
+        if min_level {
+            self.heap[a] < self.heap[b]
+        } else {
+            self.heap[a] > self.heap[b]
+        }
+    }
+
+    fn bubble_up(&mut self, mut idx: usize) {
+        if idx == 0 {
+            return;
+        }
+
+        let mut min_level = is_min_level(idx);
+        let parent = (idx - 1) / 2;
+
+        // an element misplaced against its parent belongs to the parent's
+        // kind of level instead
+        if self.above(parent, idx, min_level) {
+            self.heap.swap(idx, parent);
+            idx = parent;
+            min_level = !min_level;
+        }
+
+        while idx >= 3 {
+            let grandparent = (idx - 3) / 4;
+            if !self.above(idx, grandparent, min_level) {
+                break;
+            }
+            self.heap.swap(idx, grandparent);
+            idx = grandparent;
+        }
+    }
+
+    fn trickle_down(&mut self, mut idx: usize) {
+        let min_level = is_min_level(idx);
+        let len = self.heap.len();
+
+        loop {
+            // the best of the up to two children and four grandchildren
+            let first_child = 2 * idx + 1;
+            let candidates = [first_child, first_child + 1]
+                .into_iter()
+                .chain(2 * first_child + 1..2 * first_child + 5)
+                .filter(|&i| i < len);
+
+            let Some(best) =
+                candidates.reduce(|a, b| if self.above(b, a, min_level) { b } else { a })
+            else {
+                return;
+            };
+
+            if !self.above(best, idx, min_level) {
+                return;
+            }
+            self.heap.swap(best, idx);
+
+            if best <= first_child + 1 {
+                return;
+            }
+
+            let parent = (best - 1) / 2;
+            if self.above(parent, best, min_level) {
+                self.heap.swap(best, parent);
+            }
+            idx = best;
+        }
+    }
+}
+
+impl<T: Ord> Default for MinMaxHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn is_min_level(idx: usize) -> bool {
+    (idx + 1).ilog2().is_multiple_of(2)
+}
+
+#[test]
+fn min_max_heap_pops_both_ends() {
+    let mut heap = MinMaxHeap::new();
+    assert_eq!(None, heap.pop_max());
+
+    // bounded top-5: evict from the small end
+    for i in 0..200u32 {
+        heap.push(i * 7919 % 200);
+        if heap.len() > 5 {
+            heap.pop_min();
+        }
+    }
+    assert_eq!(Some(&195), heap.peek_min());
+    assert_eq!(Some(&199), heap.peek_max());
+
+    for i in 0..100 {
+        heap.push(i * 37 % 100);
+    }
+    let mut lows = Vec::new();
+    let mut highs = Vec::new();
+    while !heap.is_empty() {
+        lows.extend(heap.pop_min());
+        highs.extend(heap.pop_max());
+    }
+
+    assert_eq!((0..53).collect::<Vec<_>>(), lows);
+    let mut expected: Vec<_> = (53..100).chain(195..200).rev().collect();
+    expected.truncate(52);
+    assert_eq!(expected, highs);
+}
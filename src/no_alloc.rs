@@ -0,0 +1,82 @@
+use std::cell::Cell;
+
+use crate::TryReserveError;
+
+thread_local! {
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Runs `f` with vector allocation forbidden on the current thread.
+///
+/// Inside the scope any `MyVec` operation that would allocate or reallocate
+/// fails instead: the `try_*` APIs return
+/// [`TryReserveError::AllocDisallowed`] and the infallible ones panic. Use it
+/// around real-time sections so tests prove they never hit the allocator;
+/// operations that fit in existing capacity keep working.
+///
+/// Scopes nest, and the restriction is lifted even if `f` panics.
+pub fn no_alloc_scope<R>(f: impl FnOnce() -> R) -> R {
+    struct Exit;
+
+    impl Drop for Exit {
+        fn drop(&mut self) {
+            DEPTH.with(|depth| depth.set(depth.get() - 1));
+        }
+    }
+
+    DEPTH.with(|depth| depth.set(depth.get() + 1));
+    let _exit = Exit;
+
+    f()
+}
+
+/// Returns `false` inside a [`no_alloc_scope`].
+pub fn allocation_allowed() -> bool {
+    DEPTH.with(|depth| depth.get() == 0)
+}
+
+pub(crate) fn check() -> Result<(), TryReserveError> {
+    if allocation_allowed() {
+        Ok(())
+    } else {
+        Err(TryReserveError::AllocDisallowed)
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn no_alloc_scope_rejects_growth() {
+    use crate::MyVec;
+
+    let mut v = MyVec::with_capacity(2);
+
+    no_alloc_scope(|| {
+        assert!(!allocation_allowed());
+        v.push(1u32);
+        v.push(2);
+        assert_eq!(Err(TryReserveError::AllocDisallowed), v.try_push(3));
+        assert!(MyVec::<u8>::try_with_capacity(1).is_err());
+        assert!(MyVec::<u8>::try_with_capacity(0).is_ok());
+    });
+
+    assert!(allocation_allowed());
+    v.push(3);
+    assert_eq!(&[1, 2, 3], &v[..]);
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn no_alloc_scope_panics_on_infallible_growth() {
+    use crate::MyVec;
+    use std::panic;
+
+    let result = panic::catch_unwind(|| {
+        no_alloc_scope(|| {
+            let mut v = MyVec::new();
+            v.push(1u8);
+        })
+    });
+
+    assert!(result.is_err());
+    assert!(allocation_allowed());
+}
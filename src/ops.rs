@@ -0,0 +1,56 @@
+use std::ops::{Add, AddAssign};
+
+use crate::MyVec;
+
+/// Concatenates two vectors, reusing the left one's allocation.
+impl<T> Add for MyVec<T> {
+    type Output = MyVec<T>;
+
+    fn add(mut self, mut rhs: MyVec<T>) -> MyVec<T> {
+        self.append(&mut rhs);
+        self
+    }
+}
+
+impl<T: Clone> Add<&[T]> for MyVec<T> {
+    type Output = MyVec<T>;
+
+    fn add(mut self, rhs: &[T]) -> MyVec<T> {
+        self.extend_from_slice(rhs);
+        self
+    }
+}
+
+impl<T> AddAssign for MyVec<T> {
+    fn add_assign(&mut self, mut rhs: MyVec<T>) {
+        self.append(&mut rhs);
+    }
+}
+
+impl<T: Clone> AddAssign<&[T]> for MyVec<T> {
+    fn add_assign(&mut self, rhs: &[T]) {
+        self.extend_from_slice(rhs);
+    }
+}
+
+#[test]
+fn concat_operators() {
+    let mut a = MyVec::new();
+    a.push(String::from("a"));
+    let mut b = MyVec::new();
+    b.push(String::from("b"));
+
+    let mut v = a + b;
+    v += &[String::from("c")][..];
+    let mut d = MyVec::new();
+    d.push(String::from("d"));
+    v += d;
+    let v = v + &[String::from("e")][..];
+
+    assert_eq!(vec!["a", "b", "c", "d", "e"], v.iter().collect::<Vec<_>>());
+
+    let mut bytes = MyVec::new();
+    bytes += b"GET ".as_slice();
+    bytes += b"/".as_slice();
+    assert_eq!(b"GET /", &bytes[..]);
+}
@@ -0,0 +1,166 @@
+use std::mem::MaybeUninit;
+
+use crate::bitset::Bitset;
+use crate::MyVec;
+
+/// A vector of optional values, stored as a dense buffer of `T` plus a
+/// validity bitmap instead of one `Option<T>` per slot.
+///
+/// This saves the discriminant, and its padding, for types without a niche.
+/// The bitmap has bit `i % 64` of word `i / 64` set when slot `i` holds a
+/// value, which on little-endian targets is the byte layout of an Arrow
+/// validity buffer.
+pub struct OptionVec<T> {
+    values: MyVec<MaybeUninit<T>>,
+    validity: Bitset,
+}
+
+impl<T> OptionVec<T> {
+    pub fn new() -> Self {
+        Self {
+            values: MyVec::new(),
+            validity: Bitset::new(0),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the number of empty slots.
+    pub fn null_count(&self) -> usize {
+        let set: usize = self
+            .validity
+            .words()
+            .iter()
+            .map(|w| w.count_ones() as usize)
+            .sum();
+        self.len() - set
+    }
+
+    /// Returns the validity bitmap; bits past `len` are always unset.
+    pub fn validity(&self) -> &[u64] {
+        self.validity.words()
+    }
+
+    pub fn is_some(&self, idx: usize) -> bool {
+        idx < self.len() && self.validity.get(idx)
+    }
+
+    pub fn push(&mut self, value: Option<T>) {
+        match value {
+            Some(value) => self.push_some(value),
+            None => self.push_none(),
+        }
+    }
+
+    pub fn push_some(&mut self, value: T) {
+        let idx = self.len();
+        self.push_none();
+        self.values[idx].write(value);
+        self.validity.set(idx);
+    }
+
+    pub fn push_none(&mut self) {
+        self.values.push(MaybeUninit::uninit());
+        self.validity.grow_to(self.values.len());
+    }
+
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        if self.is_some(idx) {
+            Some(unsafe { self.values[idx].assume_init_ref() })
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        if self.is_some(idx) {
+            Some(unsafe { self.values[idx].assume_init_mut() })
+        } else {
+            None
+        }
+    }
+
+    /// Replaces slot `idx`, returning its old value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn set(&mut self, idx: usize, value: Option<T>) -> Option<T> {
+        let old = self.take(idx);
+
+        if let Some(value) = value {
+            self.values[idx].write(value);
+            self.validity.set(idx);
+        }
+
+        old
+    }
+
+    /// Empties slot `idx`, returning its value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn take(&mut self, idx: usize) -> Option<T> {
+        assert!(idx < self.len(), "index out of bounds");
+
+        if self.validity.get(idx) {
+            self.validity.unset(idx);
+            Some(unsafe { self.values[idx].assume_init_read() })
+        } else {
+            None
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Option<&T>> + '_ {
+        (0..self.len()).map(move |idx| self.get(idx))
+    }
+}
+
+impl<T> Default for OptionVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for OptionVec<T> {
+    fn drop(&mut self) {
+        for idx in 0..self.len() {
+            if self.validity.get(idx) {
+                unsafe { self.values[idx].assume_init_drop() }
+            }
+        }
+    }
+}
+
+#[test]
+fn option_vec_tracks_validity() {
+    let mut v = OptionVec::new();
+    for i in 0..70 {
+        if i % 3 == 0 {
+            v.push_some(i.to_string());
+        } else {
+            v.push_none();
+        }
+    }
+
+    assert_eq!(70, v.len());
+    assert_eq!(46, v.null_count());
+    assert_eq!(Some(&"3".to_string()), v.get(3));
+    assert_eq!(None, v.get(4));
+    assert_eq!(None, v.get(70));
+    assert_eq!(0b1001001, v.validity()[0] & 0b1111111);
+
+    assert_eq!(None, v.set(4, Some("four".to_string())));
+    assert_eq!(Some("3".to_string()), v.set(3, None));
+    v.get_mut(4).unwrap().push('!');
+    assert_eq!(Some("four!".to_string()), v.take(4));
+    assert_eq!(47, v.null_count());
+    assert_eq!(23, v.iter().flatten().count());
+}
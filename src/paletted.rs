@@ -0,0 +1,170 @@
+use crate::MyVec;
+
+/// A vector for low-cardinality data: each distinct value is stored once in
+/// a palette, and elements are bit-packed palette indices.
+///
+/// Indices start one bit wide and widen, repacking everything once, each
+/// time the palette outgrows them, so 200 distinct values cost 8 bits per
+/// element no matter how large `T` is. Lookups in the palette are linear,
+/// which is what keeps `T` free of `Hash`/`Ord` bounds; palettes are meant
+/// to stay small.
+pub struct PalettedVec<T> {
+    palette: MyVec<T>,
+    words: MyVec<u64>,
+    bits: u32,
+    len: usize,
+}
+
+impl<T: PartialEq> PalettedVec<T> {
+    pub fn new() -> Self {
+        Self {
+            palette: MyVec::new(),
+            words: MyVec::new(),
+            bits: 1,
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the distinct values stored so far.
+    pub fn palette(&self) -> &[T] {
+        &self.palette
+    }
+
+    /// Returns how many bits each element takes.
+    pub fn bits_per_element(&self) -> u32 {
+        self.bits
+    }
+
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        if idx < self.len {
+            Some(&self.palette[self.index(idx)])
+        } else {
+            None
+        }
+    }
+
+    pub fn push(&mut self, value: T) {
+        let id = self.intern(value);
+
+        let bit_len = (self.len + 1) * self.bits as usize;
+        while self.words.len() * 64 < bit_len {
+            self.words.push(0);
+        }
+
+        self.len += 1;
+        self.set_index(self.len - 1, id);
+    }
+
+    pub fn set(&mut self, idx: usize, value: T) {
+        assert!(idx < self.len, "index out of bounds");
+
+        let id = self.intern(value);
+        self.set_index(idx, id);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        (0..self.len).map(move |idx| &self.palette[self.index(idx)])
+    }
+
+    fn intern(&mut self, value: T) -> usize {
+        if let Some(id) = self.palette.iter().position(|v| *v == value) {
+            return id;
+        }
+
+        self.palette.push(value);
+        let id = self.palette.len() - 1;
+
+        if id >> self.bits != 0 {
+            self.widen();
+        }
+
+        id
+    }
+
+    fn widen(&mut self) {
+        let old = std::mem::take(&mut self.words);
+        let old_bits = self.bits;
+        self.bits += 1;
+
+        let bit_len = self.len * self.bits as usize;
+        self.words = MyVec::from_elem(0, bit_len.div_ceil(64));
+
+        for idx in 0..self.len {
+            let id = read_bits(&old, idx * old_bits as usize, old_bits);
+            self.set_index(idx, id);
+        }
+    }
+
+    fn index(&self, idx: usize) -> usize {
+        read_bits(&self.words, idx * self.bits as usize, self.bits)
+    }
+
+    fn set_index(&mut self, idx: usize, id: usize) {
+        let bit = idx * self.bits as usize;
+        let (word, offset) = (bit / 64, bit % 64);
+        let mask = (1u64 << self.bits) - 1;
+
+        self.words[word] &= !(mask << offset);
+        self.words[word] |= (id as u64) << offset;
+
+        // the index straddles into the next word
+        if offset + self.bits as usize > 64 {
+            let spill = 64 - offset;
+            self.words[word + 1] &= !(mask >> spill);
+            self.words[word + 1] |= (id as u64) >> spill;
+        }
+    }
+}
+
+fn read_bits(words: &[u64], bit: usize, bits: u32) -> usize {
+    let (word, offset) = (bit / 64, bit % 64);
+    let mask = (1u64 << bits) - 1;
+
+    let mut value = words[word] >> offset;
+    if offset + bits as usize > 64 {
+        value |= words[word + 1] << (64 - offset);
+    }
+
+    (value & mask) as usize
+}
+
+impl<T: PartialEq> Default for PalettedVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[test]
+fn paletted_packs_and_widens() {
+    let mut v = PalettedVec::new();
+    for i in 0..1000 {
+        v.push(if i % 3 == 0 { "stone" } else { "air" });
+    }
+    assert_eq!(1, v.bits_per_element());
+    assert_eq!(2, v.palette().len());
+
+    // a third value needs a second bit, repacking what's already stored
+    v.set(10, "water");
+    assert_eq!(2, v.bits_per_element());
+    assert_eq!(Some(&"stone"), v.get(0));
+    assert_eq!(Some(&"water"), v.get(10));
+    assert_eq!(Some(&"air"), v.get(11));
+    assert_eq!(Some(&"stone"), v.get(999));
+    assert_eq!(None, v.get(1000));
+
+    // 7-bit indices straddle word boundaries
+    let mut wide = PalettedVec::new();
+    for i in 0..300 {
+        wide.push(i % 100);
+    }
+    assert_eq!(7, wide.bits_per_element());
+    assert!(wide.iter().enumerate().all(|(i, &x)| x == i % 100));
+}
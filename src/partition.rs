@@ -0,0 +1,54 @@
+use crate::MyVec;
+
+impl<T> MyVec<T> {
+    /// Moves the elements matching `pred` to the front and returns how many
+    /// there are, keeping the relative order on both sides.
+    ///
+    /// Calls `pred` once per element and never allocates; the price for
+    /// that is O(n log n) moves, from merging the two halves with a rotate
+    /// at each level.
+    pub fn partition_in_place_stable(&mut self, mut pred: impl FnMut(&T) -> bool) -> usize {
+        partition(self, &mut pred)
+    }
+}
+
+fn partition<T>(s: &mut [T], pred: &mut impl FnMut(&T) -> bool) -> usize {
+    if s.len() <= 1 {
+        return s.iter().filter(|x| pred(x)).count();
+    }
+
+    let mid = s.len() / 2;
+    let left = partition(&mut s[..mid], pred);
+    let right = partition(&mut s[mid..], pred);
+
+    // [left yes, left no, right yes, right no] -> [left yes, right yes, ...]
+    s[left..mid + right].rotate_left(mid - left);
+
+    left + right
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn stable_partition_keeps_order() {
+    let mut v = MyVec::new();
+    for i in 0..20 {
+        v.push(i * 7 % 20);
+    }
+    let expected: Vec<_> = v
+        .iter()
+        .filter(|&&x| x % 3 == 0)
+        .chain(v.iter().filter(|&&x| x % 3 != 0))
+        .copied()
+        .collect();
+
+    let mut calls = 0;
+    let split = v.partition_in_place_stable(|&x| {
+        calls += 1;
+        x % 3 == 0
+    });
+    assert_eq!(7, split);
+    assert_eq!(20, calls);
+    assert_eq!(expected, *v);
+
+    assert_eq!(0, MyVec::<u8>::new().partition_in_place_stable(|_| true));
+}
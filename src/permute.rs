@@ -0,0 +1,59 @@
+use crate::bitset::Bitset;
+use crate::MyVec;
+
+impl<T> MyVec<T> {
+    /// Reorders the elements so that the new `self[i]` is the old
+    /// `self[perm[i]]`; applying an argsort result this way sorts the
+    /// vector.
+    ///
+    /// Elements are moved along the permutation's cycles, so this takes
+    /// O(n) time and n bits of scratch space, however large `T` is. The same
+    /// `perm` can be applied to several parallel vectors in turn.
+    ///
+    /// # Panics
+    ///
+    /// Panics, before moving anything, if `perm` is not a permutation of
+    /// `0..self.len()`.
+    pub fn apply_permutation(&mut self, perm: &[usize]) {
+        assert_eq!(self.len, perm.len(), "permutation length mismatch");
+
+        let mut visited = Bitset::new(perm.len());
+        for &p in perm {
+            assert!(p < perm.len() && !visited.get(p), "not a permutation");
+            visited.set(p);
+        }
+
+        let mut visited = Bitset::new(perm.len());
+        for start in 0..perm.len() {
+            let mut j = start;
+            while !visited.get(j) {
+                visited.set(j);
+                let k = perm[j];
+                if k == start {
+                    break;
+                }
+                self.swap(j, k);
+                j = k;
+            }
+        }
+    }
+}
+
+#[test]
+fn apply_permutation_to_parallel_vecs() {
+    let mut names = MyVec::new();
+    let mut ages = MyVec::new();
+    for (name, age) in [("carol", 41), ("alice", 29), ("dave", 35), ("bob", 52)] {
+        names.push(name.to_string());
+        ages.push(age);
+    }
+
+    let perm = [1, 3, 0, 2];
+    names.apply_permutation(&perm);
+    ages.apply_permutation(&perm);
+    assert_eq!(["alice", "bob", "carol", "dave"], *names);
+    assert_eq!([29, 52, 41, 35], *ages);
+
+    let result = std::panic::catch_unwind(move || ages.apply_permutation(&[0, 0, 1, 2]));
+    assert!(result.is_err());
+}
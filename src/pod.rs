@@ -0,0 +1,102 @@
+use std::mem;
+use std::slice;
+
+use crate::{MyVec, Zeroable};
+
+/// Plain-old-data types: `Copy`, without padding, and valid for any bit
+/// pattern, so they can be viewed as and rebuilt from raw bytes.
+///
+/// # Safety
+///
+/// Implementors must have no padding bytes and must accept every bit pattern
+/// as a valid value.
+pub unsafe trait Pod: Zeroable + Copy + 'static {}
+
+macro_rules! impl_pod {
+    ($($t:ty),*) => {
+        $(unsafe impl Pod for $t {})*
+    };
+}
+
+impl_pod!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+
+impl<T: Pod> MyVec<T> {
+    /// Views the elements as their raw in-memory bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe {
+            slice::from_raw_parts(self.as_ptr() as *const u8, self.len() * mem::size_of::<T>())
+        }
+    }
+
+    /// Views the elements as mutable raw bytes; any bytes written form valid
+    /// elements.
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        let len = self.len() * mem::size_of::<T>();
+
+        unsafe { slice::from_raw_parts_mut(self.as_mut_ptr() as *mut u8, len) }
+    }
+
+    /// Like [`from_elem`](MyVec::from_elem), but an all-zero `elem` gets its
+    /// buffer straight from zeroed memory instead of being written `n` times.
+    #[cfg(not(no_global_oom_handling))]
+    pub fn from_elem_pod(elem: T, n: usize) -> Self {
+        if is_zero(&elem) {
+            Self::zeroed(n)
+        } else {
+            Self::from_elem(elem, n)
+        }
+    }
+
+    /// Like [`resize`](MyVec::resize), but an all-zero `value` is filled in
+    /// through [`resize_zeroed`](MyVec::resize_zeroed).
+    #[cfg(not(no_global_oom_handling))]
+    pub fn resize_pod(&mut self, new_len: usize, value: T) {
+        if is_zero(&value) {
+            self.resize_zeroed(new_len);
+        } else {
+            self.resize(new_len, value);
+        }
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+fn is_zero<T: Pod>(value: &T) -> bool {
+    // no padding, so every byte of the value is initialized
+    let bytes =
+        unsafe { slice::from_raw_parts(value as *const T as *const u8, mem::size_of::<T>()) };
+
+    bytes.iter().all(|&b| b == 0)
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn pod_bytes() {
+    let mut v = MyVec::<u16>::zeroed(2);
+    v[0] = 0x0102;
+    assert_eq!(4, v.as_bytes().len());
+    assert_eq!(0x0102u16.to_ne_bytes(), v.as_bytes()[..2]);
+
+    v.as_bytes_mut()[2..].copy_from_slice(&7u16.to_ne_bytes());
+    assert_eq!(7, v[1]);
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn from_elem_pod_zero_fast_path() {
+    let v = MyVec::from_elem_pod(0u32, 1000);
+    assert_eq!(1000, v.len());
+    assert!(v.iter().all(|&x| x == 0));
+
+    let mut v = MyVec::from_elem_pod([1.5f32; 2], 3);
+    assert_eq!([1.5; 2], v[2]);
+    v.resize_pod(5, [0.0; 2]);
+    assert_eq!(&[[1.5; 2], [1.5; 2], [1.5; 2], [0.0; 2], [0.0; 2]], &v[..]);
+    v.resize_pod(6, [2.0; 2]);
+    assert_eq!([2.0; 2], v[5]);
+
+    // -0.0 is not all zero bytes, so it takes the copying path
+    let v = MyVec::from_elem_pod(-0.0f64, 2);
+    assert!(v[1].is_sign_negative());
+}
@@ -0,0 +1,138 @@
+use std::mem;
+use std::ops::{Deref, DerefMut};
+use std::sync::Mutex;
+
+use crate::MyVec;
+
+/// A pool of reusable `MyVec<u8>` scratch buffers.
+///
+/// Buffers handed out by [`BufferPool::get`] go back to the pool when their
+/// guard is dropped, as long as the pool retains no more than
+/// `max_retained_bytes` of capacity in total; anything beyond that is freed.
+pub struct BufferPool {
+    inner: Mutex<PoolInner>,
+    max_retained_bytes: usize,
+}
+
+struct PoolInner {
+    free: MyVec<MyVec<u8>>,
+    retained_bytes: usize,
+}
+
+impl BufferPool {
+    pub fn new(max_retained_bytes: usize) -> Self {
+        Self {
+            inner: Mutex::new(PoolInner {
+                free: MyVec::new(),
+                retained_bytes: 0,
+            }),
+            max_retained_bytes,
+        }
+    }
+
+    /// Returns an empty buffer with at least `min_capacity` bytes of capacity,
+    /// reusing the smallest pooled buffer that is large enough.
+    pub fn get(&self, min_capacity: usize) -> PooledBuffer<'_> {
+        let reused = {
+            let mut inner = self.inner.lock().unwrap();
+
+            let best = inner
+                .free
+                .iter()
+                .enumerate()
+                .filter(|(_, buf)| buf.capacity() >= min_capacity)
+                .min_by_key(|(_, buf)| buf.capacity())
+                .map(|(i, _)| i);
+
+            best.map(|i| {
+                let last = inner.free.len() - 1;
+                inner.free.swap(i, last);
+                let buf = inner.free.pop().unwrap();
+                inner.retained_bytes -= buf.capacity();
+                buf
+            })
+        };
+
+        PooledBuffer {
+            pool: self,
+            buf: reused.unwrap_or_else(|| MyVec::with_capacity(min_capacity)),
+        }
+    }
+
+    /// Returns the total capacity of the buffers currently held by the pool.
+    pub fn retained_bytes(&self) -> usize {
+        self.inner.lock().unwrap().retained_bytes
+    }
+
+    fn put_back(&self, mut buf: MyVec<u8>) {
+        if buf.capacity() == 0 {
+            return;
+        }
+
+        let mut inner = self.inner.lock().unwrap();
+        if inner.retained_bytes + buf.capacity() <= self.max_retained_bytes {
+            buf.clear();
+            inner.retained_bytes += buf.capacity();
+            inner.free.push(buf);
+        }
+    }
+}
+
+/// A buffer on loan from a [`BufferPool`], returned to it on drop.
+pub struct PooledBuffer<'a> {
+    pool: &'a BufferPool,
+    buf: MyVec<u8>,
+}
+
+impl PooledBuffer<'_> {
+    /// Keeps the buffer instead of returning it to the pool.
+    pub fn into_inner(mut self) -> MyVec<u8> {
+        mem::take(&mut self.buf)
+    }
+}
+
+impl Deref for PooledBuffer<'_> {
+    type Target = MyVec<u8>;
+
+    fn deref(&self) -> &MyVec<u8> {
+        &self.buf
+    }
+}
+
+impl DerefMut for PooledBuffer<'_> {
+    fn deref_mut(&mut self) -> &mut MyVec<u8> {
+        &mut self.buf
+    }
+}
+
+impl Drop for PooledBuffer<'_> {
+    fn drop(&mut self) {
+        self.pool.put_back(mem::take(&mut self.buf));
+    }
+}
+
+#[test]
+fn pool_reuses_buffers() {
+    let pool = BufferPool::new(64);
+
+    let mut buf = pool.get(16);
+    assert!(buf.capacity() >= 16);
+    buf.push(1);
+    let ptr = buf.as_ptr();
+    drop(buf);
+    assert_eq!(16, pool.retained_bytes());
+
+    let buf = pool.get(8);
+    assert_eq!(ptr, buf.as_ptr());
+    assert!(buf.is_empty());
+    assert_eq!(0, pool.retained_bytes());
+    drop(buf);
+
+    // over budget, so freed instead of retained
+    drop(pool.get(100));
+    assert_eq!(16, pool.retained_bytes());
+
+    let kept = pool.get(4).into_inner();
+    assert_eq!(16, kept.capacity());
+    assert_eq!(0, pool.retained_bytes());
+}
@@ -0,0 +1,65 @@
+use std::mem;
+use std::ops::Range;
+
+use crate::MyVec;
+
+const CACHE_LINE: usize = 64;
+
+impl<T> MyVec<T> {
+    /// Hints the CPU to start loading the elements in `range` into cache, so
+    /// the memory fetch overlaps with whatever work comes before they are
+    /// read.
+    ///
+    /// This is only a hint: it never faults, has no observable effect on the
+    /// contents and is a no-op on targets without a prefetch instruction.
+    pub fn prefetch(&self, range: Range<usize>) {
+        assert!(
+            range.start <= range.end && range.end <= self.len(),
+            "range out of bounds"
+        );
+
+        let elem_size = mem::size_of::<T>();
+        if elem_size == 0 || range.is_empty() {
+            return;
+        }
+
+        let start = self.as_ptr().wrapping_add(range.start) as *const u8;
+        let end = start.addr() + (range.end - range.start) * elem_size;
+
+        // step from the start of the first line, so a range straddling a
+        // line boundary gets its last line too
+        let mut line = start.wrapping_byte_sub(start.addr() % CACHE_LINE);
+        while line.addr() < end {
+            prefetch_line(line);
+            line = line.wrapping_add(CACHE_LINE);
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+fn prefetch_line(ptr: *const u8) {
+    use std::arch::x86_64::{_mm_prefetch, _MM_HINT_T0};
+
+    // prefetching is a hint and cannot fault, even on bad addresses
+    unsafe { _mm_prefetch(ptr as *const i8, _MM_HINT_T0) }
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+fn prefetch_line(_ptr: *const u8) {}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn prefetch_is_transparent() {
+    let mut v = MyVec::new();
+    for i in 0..1000u64 {
+        v.push(i);
+    }
+
+    v.prefetch(0..1000);
+    v.prefetch(500..500);
+    assert_eq!(499500, v.iter().sum::<u64>());
+
+    let mut zst = MyVec::new();
+    zst.push(());
+    zst.prefetch(0..1);
+}
@@ -0,0 +1,148 @@
+use std::mem;
+use std::ptr;
+
+use crate::MyVec;
+
+/// Integer keys usable by [`MyVec::sort_by_radix_key`].
+pub trait RadixKey: Copy {
+    /// How many low bytes of [`radix_key`](Self::radix_key) are significant.
+    const BYTES: usize;
+
+    /// Maps the key to an unsigned integer with the same ordering.
+    fn radix_key(self) -> u64;
+}
+
+macro_rules! impl_radix_unsigned {
+    ($($t:ty),*) => {
+        $(impl RadixKey for $t {
+            const BYTES: usize = mem::size_of::<$t>();
+
+            fn radix_key(self) -> u64 {
+                self as u64
+            }
+        })*
+    };
+}
+
+macro_rules! impl_radix_signed {
+    ($($t:ty => $u:ty),*) => {
+        $(impl RadixKey for $t {
+            const BYTES: usize = mem::size_of::<$t>();
+
+            fn radix_key(self) -> u64 {
+                // flipping the sign bit orders negatives before positives
+                (self as $u ^ (1 << (<$u>::BITS - 1))) as u64
+            }
+        })*
+    };
+}
+
+impl_radix_unsigned!(u8, u16, u32, u64, usize);
+impl_radix_signed!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, isize => usize);
+
+impl<T: RadixKey> MyVec<T> {
+    /// Sorts integers with a stable LSD radix sort, in O(n) per key byte.
+    pub fn sort_radix(&mut self) {
+        self.sort_by_radix_key(|x| *x);
+    }
+}
+
+impl<T> MyVec<T> {
+    /// Stably sorts by an integer key with an LSD radix sort, using a scratch
+    /// buffer the size of the vector. `key` is called once per element.
+    ///
+    /// Byte positions where every key agrees are skipped, so sorting small
+    /// values stored in wide integers stays cheap.
+    pub fn sort_by_radix_key<K, F>(&mut self, mut key: F)
+    where
+        K: RadixKey,
+        F: FnMut(&T) -> K,
+    {
+        let len = self.len();
+        if len < 2 || mem::size_of::<T>() == 0 {
+            return;
+        }
+
+        let mut keys = MyVec::with_capacity(len);
+        for elem in self.iter() {
+            keys.push(key(elem).radix_key());
+        }
+        let mut next_keys = MyVec::<u64>::zeroed(len);
+
+        // the scratch vector stays empty, only lending its buffer, so the
+        // elements are never owned twice
+        let mut scratch = MyVec::<T>::with_capacity(len);
+        let mut src = self.as_mut_ptr();
+        let mut dst = scratch.as_mut_ptr();
+
+        for pass in 0..K::BYTES {
+            let shift = 8 * pass;
+
+            let mut counts = [0usize; 256];
+            for &k in keys.iter() {
+                counts[(k >> shift) as usize & 0xff] += 1;
+            }
+            if counts.contains(&len) {
+                continue;
+            }
+
+            let mut offsets = [0usize; 256];
+            let mut total = 0;
+            for (offset, count) in offsets.iter_mut().zip(counts) {
+                *offset = total;
+                total += count;
+            }
+
+            for (i, &k) in keys.iter().enumerate() {
+                let bucket = (k >> shift) as usize & 0xff;
+                let to = offsets[bucket];
+                offsets[bucket] += 1;
+
+                next_keys[to] = k;
+                unsafe { ptr::copy_nonoverlapping(src.add(i), dst.add(to), 1) }
+            }
+
+            mem::swap(&mut keys, &mut next_keys);
+            mem::swap(&mut src, &mut dst);
+        }
+
+        if src != self.as_mut_ptr() {
+            unsafe { ptr::copy_nonoverlapping(src, self.as_mut_ptr(), len) }
+        }
+    }
+}
+
+#[test]
+fn radix_sorts_integers() {
+    let mut v = MyVec::new();
+    let mut x = 0x2545f4914f6cdd1du64;
+    for _ in 0..1000 {
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        v.push(x as i64);
+    }
+    v.push(i64::MIN);
+    v.push(i64::MAX);
+    v.push(0);
+
+    let mut expected: Vec<i64> = v.iter().copied().collect();
+    expected.sort();
+    v.sort_radix();
+    assert_eq!(&expected[..], &v[..]);
+}
+
+#[test]
+fn radix_key_sort_is_stable() {
+    let mut v = MyVec::new();
+    for (i, word) in ["d", "b", "a", "c", "b", "a"].iter().enumerate() {
+        v.push((word.to_string(), i));
+    }
+
+    v.sort_by_radix_key(|(word, _)| word.as_bytes()[0]);
+    let sorted: Vec<_> = v.iter().map(|(w, i)| (w.as_str(), *i)).collect();
+    assert_eq!(
+        vec![("a", 2), ("a", 5), ("b", 1), ("b", 4), ("c", 3), ("d", 0)],
+        sorted
+    );
+}
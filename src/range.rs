@@ -0,0 +1,128 @@
+use crate::{Float, MyVec};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The numeric types [`MyVec::arange`] can step through.
+///
+/// This trait is sealed and cannot be implemented outside this crate.
+pub trait Arange: Copy + sealed::Sealed {
+    #[doc(hidden)]
+    fn arange_len(start: Self, stop: Self, step: Self) -> usize;
+
+    #[doc(hidden)]
+    fn nth(start: Self, step: Self, n: usize) -> Self;
+}
+
+macro_rules! impl_arange_int {
+    ($($t:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl Arange for $t {
+                fn arange_len(start: Self, stop: Self, step: Self) -> usize {
+                    assert!(step != 0, "step must be non-zero");
+
+                    let (start, stop, step) = (start as i128, stop as i128, step as i128);
+                    if (step > 0 && stop > start) || (step < 0 && stop < start) {
+                        let len = (stop - start) / step + ((stop - start) % step != 0) as i128;
+                        usize::try_from(len).expect("capacity overflow")
+                    } else {
+                        0
+                    }
+                }
+
+                fn nth(start: Self, step: Self, n: usize) -> Self {
+                    (start as i128 + n as i128 * step as i128) as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_arange_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+macro_rules! impl_arange_float {
+    ($($t:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl Arange for $t {
+                fn arange_len(start: Self, stop: Self, step: Self) -> usize {
+                    assert!(step != 0.0 && step.is_finite(), "step must be finite and non-zero");
+
+                    // `as` saturates, sending a negative or NaN count to 0
+                    ((stop - start) / step).ceil() as usize
+                }
+
+                fn nth(start: Self, step: Self, n: usize) -> Self {
+                    start + n as $t * step
+                }
+            }
+        )*
+    };
+}
+
+impl_arange_float!(f32, f64);
+
+impl<T: Arange> MyVec<T> {
+    /// Returns `start`, `start + step`, `start + 2 * step`, ... up to but
+    /// excluding `stop`, in a single exact allocation.
+    ///
+    /// Each element is computed from its index rather than by repeated
+    /// addition, so float ranges don't accumulate rounding error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero, or for floats, not finite, and with
+    /// "capacity overflow" if the range has more elements than fit in memory.
+    pub fn arange(start: T, stop: T, step: T) -> Self {
+        let mut v = MyVec::new();
+        v.resize_with_index(T::arange_len(start, stop, step), |i| T::nth(start, step, i));
+        v
+    }
+}
+
+impl<T: Float> MyVec<T> {
+    /// Returns `n` evenly spaced values from `start` to `stop`, both
+    /// included, in a single exact allocation.
+    pub fn linspace(start: T, stop: T, n: usize) -> Self {
+        let mut v = MyVec::new();
+
+        if n == 1 {
+            v.push(start);
+        } else if n > 1 {
+            let step = (stop - start) / T::from_usize(n - 1);
+            v.resize_with_index(n, |i| {
+                if i == n - 1 {
+                    stop
+                } else {
+                    start + T::from_usize(i) * step
+                }
+            });
+        }
+
+        v
+    }
+}
+
+#[test]
+fn numeric_ranges() {
+    assert_eq!([0, 3, 6, 9], *MyVec::arange(0, 10, 3));
+    assert_eq!([5, 3, 1], *MyVec::arange(5i8, 0, -2));
+    assert_eq!([250, 251], *MyVec::arange(250u8, 252, 1));
+    assert!(MyVec::arange(3u32, 3, 1).is_empty());
+
+    let tenths = MyVec::arange(0.0, 1.0, 0.1);
+    assert_eq!(10, tenths.len());
+    assert_eq!(10, tenths.capacity());
+    assert!((tenths[9] - 0.9f64).abs() < 1e-12);
+
+    assert_eq!(
+        [0.0, 0.25, 0.5, 0.75, 1.0],
+        *MyVec::linspace(0.0f32, 1.0, 5)
+    );
+    assert_eq!([2.0], *MyVec::linspace(2.0, 3.0, 1));
+    assert!(MyVec::<f64>::linspace(0.0, 1.0, 0).is_empty());
+}
@@ -0,0 +1,101 @@
+use std::io::{self, Read};
+use std::ptr;
+use std::slice;
+
+use crate::MyVec;
+
+impl MyVec<u8> {
+    /// Reads up to `max` bytes from `reader` straight into the spare
+    /// capacity, growing it if needed, and returns how many bytes were read.
+    ///
+    /// Like `Read::read`, this is a single read call, so it may return fewer
+    /// bytes than `max` and `0` means end of input. Interrupted reads are
+    /// retried.
+    ///
+    /// The window handed to `reader` has to be initialized, so bytes of it
+    /// are zeroed the first time they are exposed; spare bytes a previous
+    /// call zeroed or a reader left behind are not cleared again, so a loop
+    /// of short reads with a large `max` only pays for the zeroing once.
+    pub fn extend_from_read<R: Read + ?Sized>(
+        &mut self,
+        reader: &mut R,
+        max: usize,
+    ) -> io::Result<usize> {
+        self.reserve(max);
+
+        // `Read` implementations may look at the buffer they are handed, so
+        // it has to be initialized; on stable the only sound way is to zero
+        // it. Whatever lies below the buffer's initialized mark already is.
+        let end = self.len + max;
+        let spare = unsafe {
+            let start = self.ptr().add(self.len);
+            let zeroed_from = self.buf.init.clamp(self.len, end);
+            ptr::write_bytes(self.ptr().add(zeroed_from), 0, end - zeroed_from);
+            slice::from_raw_parts_mut(start, max)
+        };
+        self.buf.init = self.buf.init.max(end);
+
+        let read = loop {
+            match reader.read(spare) {
+                Ok(n) => break n,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            }
+        };
+
+        assert!(
+            read <= max,
+            "reader reported more bytes than the buffer holds"
+        );
+
+        self.len += read;
+        self.record_len();
+
+        Ok(read)
+    }
+}
+
+#[test]
+fn extend_from_read_appends() {
+    let data: Vec<u8> = (0..100).collect();
+    let mut reader = &data[..];
+
+    let mut buf = MyVec::new();
+    buf.push(255);
+    assert_eq!(40, buf.extend_from_read(&mut reader, 40).unwrap());
+    assert_eq!(41, buf.len());
+    assert_eq!(60, buf.extend_from_read(&mut reader, 100).unwrap());
+    assert_eq!(0, buf.extend_from_read(&mut reader, 100).unwrap());
+
+    assert_eq!(255, buf[0]);
+    assert_eq!(&data[..], &buf[1..]);
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn extend_from_read_zeroes_spare_bytes_once() {
+    // fills the whole window but only reports the first byte as read
+    struct Scribble(u8);
+    impl Read for Scribble {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.0 = buf[buf.len() - 1];
+            buf.fill(0xaa);
+            Ok(1)
+        }
+    }
+
+    let mut buf = MyVec::new();
+    let mut reader = Scribble(0);
+    assert_eq!(1, buf.extend_from_read(&mut reader, 16).unwrap());
+    assert_eq!(0, reader.0);
+
+    // the bytes the reader left behind are still there, not zeroed again
+    assert_eq!(1, buf.extend_from_read(&mut reader, 15).unwrap());
+    assert_eq!(0xaa, reader.0);
+
+    // handing out the spare capacity forgets what was initialized
+    buf.as_mut_ptr();
+    assert_eq!(1, buf.extend_from_read(&mut reader, 14).unwrap());
+    assert_eq!(0, reader.0);
+    assert_eq!(&[0xaa; 3], &buf[..]);
+}
@@ -0,0 +1,91 @@
+use std::mem::{self, ManuallyDrop};
+use std::ptr;
+
+use crate::MyVec;
+
+impl<T, const N: usize> MyVec<[T; N]> {
+    /// Flattens a vector of arrays into a vector of their elements, reusing
+    /// the allocation.
+    pub fn into_flattened(self) -> MyVec<T> {
+        let len = self.len.checked_mul(N).expect("vec len overflow");
+
+        let cap = if mem::size_of::<T>() == 0 {
+            usize::MAX
+        } else if N == 0 {
+            0
+        } else {
+            // a non-zero-sized buffer holds at most isize::MAX bytes
+            self.cap() * N
+        };
+
+        let this = ManuallyDrop::new(self);
+        let buf = unsafe { ptr::read(&this.buf).cast(cap) };
+
+        MyVec { buf, len }
+    }
+}
+
+impl<T> MyVec<T> {
+    /// Regroups the elements into arrays of `N`, reusing the allocation.
+    ///
+    /// Fails, handing the vector back, unless both the length and the
+    /// capacity are multiples of `N`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    pub fn into_arrays<const N: usize>(self) -> Result<MyVec<[T; N]>, Self> {
+        assert!(N != 0, "array length must be non-zero");
+
+        let cap = if mem::size_of::<T>() == 0 {
+            usize::MAX
+        } else if self.cap().is_multiple_of(N) {
+            self.cap() / N
+        } else {
+            return Err(self);
+        };
+
+        if !self.len.is_multiple_of(N) {
+            return Err(self);
+        }
+
+        let len = self.len / N;
+        let this = ManuallyDrop::new(self);
+        let buf = unsafe { ptr::read(&this.buf).cast(cap) };
+
+        Ok(MyVec { buf, len })
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn regroup_round_trip() {
+    let mut v = MyVec::with_capacity(6);
+    for i in 0..6 {
+        v.push(i.to_string());
+    }
+    let ptr = v.as_ptr();
+
+    let pairs = v.into_arrays::<2>().ok().unwrap();
+    assert_eq!(3, pairs.len());
+    assert_eq!(3, pairs.capacity());
+    assert_eq!(["2", "3"], pairs[1]);
+
+    let flat = pairs.into_flattened();
+    assert_eq!(ptr, flat.as_ptr());
+    assert_eq!(6, flat.capacity());
+    assert_eq!(
+        vec!["0", "1", "2", "3", "4", "5"],
+        flat.iter().collect::<Vec<_>>()
+    );
+
+    // capacity 6 is not a multiple of 4
+    let flat = flat.into_arrays::<4>().err().unwrap();
+    assert_eq!(6, flat.len());
+
+    let mut units = MyVec::new();
+    for _ in 0..4 {
+        units.push(());
+    }
+    assert_eq!(2, units.into_arrays::<2>().ok().unwrap().len());
+}
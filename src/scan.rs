@@ -0,0 +1,50 @@
+use std::ops::Add;
+
+use crate::MyVec;
+
+impl<T> MyVec<T> {
+    /// Replaces each element with the sum of itself and everything before
+    /// it.
+    ///
+    /// For an offset table, where each entry is the sum of the lengths
+    /// *before* it, push the lengths after a leading zero.
+    pub fn prefix_sum(&mut self)
+    where
+        T: Copy + Add<Output = T>,
+    {
+        for i in 1..self.len {
+            self[i] = self[i - 1] + self[i];
+        }
+    }
+
+    /// Replaces each element `x` with `acc = op(&acc, x)`, starting from
+    /// `acc = init`; `init` should be the identity of `op`.
+    pub fn scan_in_place(&mut self, init: T, mut op: impl FnMut(&T, &T) -> T)
+    where
+        T: Clone,
+    {
+        let mut acc = init;
+
+        for x in self.iter_mut() {
+            acc = op(&acc, x);
+            x.clone_from(&acc);
+        }
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn scan_cumulates_in_place() {
+    let mut lengths = MyVec::new();
+    for len in [3usize, 0, 5, 2] {
+        lengths.push(len);
+    }
+
+    let mut sums = MyVec::new();
+    sums.extend_from_slice(&lengths);
+    sums.prefix_sum();
+    assert_eq!([3, 3, 8, 10], *sums);
+
+    lengths.scan_in_place(1, |acc, &x| acc.max(&x) * 2);
+    assert_eq!([6, 12, 24, 48], *lengths);
+}
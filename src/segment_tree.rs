@@ -0,0 +1,114 @@
+use std::ops::Range;
+
+use crate::MyVec;
+
+/// A segment tree answering range queries under an associative `op` with an
+/// `identity` element (sum with 0, min with `MAX`, ...) in O(log n).
+///
+/// The tree is stored flat: leaves occupy `tree[n..2n]` and every internal
+/// node `i` combines its children `2i` and `2i + 1`.
+pub struct SegmentTree<T, Op> {
+    tree: MyVec<T>,
+    len: usize,
+    identity: T,
+    op: Op,
+}
+
+impl<T: Clone, Op: Fn(&T, &T) -> T> SegmentTree<T, Op> {
+    pub fn new(values: &[T], identity: T, op: Op) -> Self {
+        let len = values.len();
+        let mut tree = MyVec::with_capacity(2 * len);
+
+        for _ in 0..len {
+            tree.push(identity.clone());
+        }
+        for value in values {
+            tree.push(value.clone());
+        }
+        for i in (1..len).rev() {
+            tree[i] = op(&tree[2 * i], &tree[2 * i + 1]);
+        }
+
+        Self {
+            tree,
+            len,
+            identity,
+            op,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, idx: usize) -> &T {
+        assert!(idx < self.len, "index out of bounds");
+
+        &self.tree[self.len + idx]
+    }
+
+    pub fn set(&mut self, idx: usize, value: T) {
+        assert!(idx < self.len, "index out of bounds");
+
+        let mut i = self.len + idx;
+        self.tree[i] = value;
+
+        while i > 1 {
+            i /= 2;
+            self.tree[i] = (self.op)(&self.tree[2 * i], &self.tree[2 * i + 1]);
+        }
+    }
+
+    /// Combines the elements in `range`, in order, returning the identity for
+    /// an empty range.
+    pub fn query(&self, range: Range<usize>) -> T {
+        assert!(
+            range.start <= range.end && range.end <= self.len,
+            "range out of bounds"
+        );
+
+        // left and right partial results are kept apart so `op` need not be
+        // commutative
+        let mut left = self.identity.clone();
+        let mut right = self.identity.clone();
+        let (mut lo, mut hi) = (range.start + self.len, range.end + self.len);
+
+        while lo < hi {
+            if lo % 2 == 1 {
+                left = (self.op)(&left, &self.tree[lo]);
+                lo += 1;
+            }
+            if hi % 2 == 1 {
+                hi -= 1;
+                right = (self.op)(&self.tree[hi], &right);
+            }
+            lo /= 2;
+            hi /= 2;
+        }
+
+        (self.op)(&left, &right)
+    }
+}
+
+#[test]
+fn segment_tree_sum_and_min() {
+    let mut sums = SegmentTree::new(&[5, 3, 8, 1, 4], 0, |a, b| a + b);
+    assert_eq!(21, sums.query(0..5));
+    assert_eq!(12, sums.query(1..4));
+    assert_eq!(0, sums.query(2..2));
+    sums.set(2, 0);
+    assert_eq!(4, sums.query(1..4));
+
+    let mins = SegmentTree::new(&[5, 3, 8, 1, 4], i32::MAX, |a, b| *a.min(b));
+    assert_eq!(3, mins.query(0..3));
+    assert_eq!(1, mins.query(2..5));
+
+    // string concatenation is associative but not commutative
+    let words = ["a", "b", "c", "d", "e"].map(String::from);
+    let concat = SegmentTree::new(&words, String::new(), |a, b| format!("{a}{b}"));
+    assert_eq!("bcde", concat.query(1..5));
+}
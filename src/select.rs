@@ -0,0 +1,75 @@
+use std::cmp::Ordering;
+
+use crate::MyVec;
+
+impl<T> MyVec<T> {
+    /// Reorders the vector so its `k` smallest elements come first, in no
+    /// particular order, and returns them.
+    ///
+    /// Runs in O(n) on average, against O(n log n) for a full sort. If `k`
+    /// is at least the length, the whole vector is returned untouched.
+    pub fn select_bottom_k(&mut self, k: usize) -> &mut [T]
+    where
+        T: Ord,
+    {
+        self.select_k_by(k, T::cmp)
+    }
+
+    /// Like [`MyVec::select_bottom_k`], but for the `k` largest elements.
+    pub fn select_top_k(&mut self, k: usize) -> &mut [T]
+    where
+        T: Ord,
+    {
+        self.select_k_by(k, |a, b| b.cmp(a))
+    }
+
+    pub fn select_bottom_k_by_key<K: Ord>(
+        &mut self,
+        k: usize,
+        mut f: impl FnMut(&T) -> K,
+    ) -> &mut [T] {
+        self.select_k_by(k, |a, b| f(a).cmp(&f(b)))
+    }
+
+    pub fn select_top_k_by_key<K: Ord>(
+        &mut self,
+        k: usize,
+        mut f: impl FnMut(&T) -> K,
+    ) -> &mut [T] {
+        self.select_k_by(k, |a, b| f(b).cmp(&f(a)))
+    }
+
+    fn select_k_by(&mut self, k: usize, cmp: impl FnMut(&T, &T) -> Ordering) -> &mut [T] {
+        if k == 0 {
+            return &mut [];
+        }
+
+        if k < self.len {
+            self.select_nth_unstable_by(k - 1, cmp);
+        }
+
+        let k = k.min(self.len);
+        &mut self[..k]
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn select_k_partitions() {
+    let mut v = MyVec::new();
+    for i in 0..100u32 {
+        v.push(i * 37 % 100);
+    }
+
+    let mut top = v.select_top_k(3).to_vec();
+    top.sort();
+    assert_eq!(vec![97, 98, 99], top);
+    assert!(v[3..].iter().all(|&x| x < 97));
+
+    let mut near = v.select_bottom_k_by_key(3, |&x| x.abs_diff(50)).to_vec();
+    near.sort();
+    assert_eq!(vec![49, 50, 51], near);
+
+    assert!(v.select_bottom_k(0).is_empty());
+    assert_eq!(100, v.select_top_k(1000).len());
+}
@@ -0,0 +1,92 @@
+use std::ops::{Bound, Deref, RangeBounds};
+use std::sync::Arc;
+
+use crate::MyVec;
+
+/// An immutable, reference-counted view into a frozen [`MyVec`].
+///
+/// Cloning and slicing only bump the reference count; the elements are
+/// dropped along with the last view.
+pub struct SharedVec<T> {
+    data: Arc<MyVec<T>>,
+    start: usize,
+    end: usize,
+}
+
+impl<T> MyVec<T> {
+    /// Turns the vector into a [`SharedVec`] without copying its elements.
+    pub fn freeze(self) -> SharedVec<T> {
+        let end = self.len();
+
+        SharedVec {
+            data: Arc::new(self),
+            start: 0,
+            end,
+        }
+    }
+}
+
+impl<T> SharedVec<T> {
+    /// Returns a view of `range`, relative to this view, sharing the same
+    /// storage.
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> Self {
+        let len = self.end - self.start;
+
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n.checked_add(1).expect("range out of bounds"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n.checked_add(1).expect("range out of bounds"),
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => len,
+        };
+
+        assert!(start <= end && end <= len, "range out of bounds");
+
+        Self {
+            data: Arc::clone(&self.data),
+            start: self.start + start,
+            end: self.start + end,
+        }
+    }
+}
+
+impl<T> Clone for SharedVec<T> {
+    fn clone(&self) -> Self {
+        Self {
+            data: Arc::clone(&self.data),
+            start: self.start,
+            end: self.end,
+        }
+    }
+}
+
+impl<T> Deref for SharedVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.data[self.start..self.end]
+    }
+}
+
+#[test]
+fn freeze_and_slice() {
+    let mut v = MyVec::new();
+    for i in 0..10 {
+        v.push(Box::new(i));
+    }
+
+    let shared = v.freeze();
+    let copy = shared.clone();
+    let middle = shared.slice(2..8);
+    let inner = middle.slice(1..=2);
+    drop(shared);
+
+    assert_eq!(10, copy.len());
+    assert_eq!(6, middle.len());
+    assert_eq!(vec![3, 4], inner.iter().map(|b| **b).collect::<Vec<_>>());
+    assert_eq!(9, *copy[9]);
+    assert!(middle.slice(6..).is_empty());
+}
@@ -0,0 +1,344 @@
+use std::alloc::Layout;
+use std::ffi::CStr;
+use std::io;
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::{Deref, DerefMut, Range};
+use std::os::fd::{AsFd, BorrowedFd, OwnedFd};
+use std::ptr::NonNull;
+use std::slice;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::{sys, Advice, Pod};
+
+const MAGIC: u64 = u64::from_le_bytes(*b"myvecshm");
+
+/// Sits at the start of the shared mapping, followed by the elements.
+#[repr(C)]
+struct Header {
+    magic: u64,
+    elem_size: u64,
+    capacity: u64,
+    len: AtomicU64, // the published length
+}
+
+/// A fixed-capacity vector of [`Pod`] elements living in shared memory, so
+/// several processes can map the same elements without copying them.
+///
+/// Every handle keeps its own view of the length. Writers make their pushes
+/// visible to other handles with [`publish`](Self::publish), and readers pick
+/// up the latest published length with [`refresh`](Self::refresh); the pair
+/// orders the element writes before the reads.
+///
+/// The slices a handle derefs to alias memory other handles can write, which
+/// the borrow checker cannot see. Getting a second handle onto the same
+/// memory is therefore `unsafe`, see [`open`](Self::open) and
+/// [`from_fd`](Self::from_fd) for the contract.
+pub struct ShmVec<T: Pod> {
+    fd: OwnedFd,
+    map: NonNull<u8>,
+    map_len: usize,
+    data: NonNull<T>,
+    capacity: usize,
+    len: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Pod> ShmVec<T> {
+    /// Creates the named shared memory object `name` (for example
+    /// `"/samples"`) with room for `capacity` elements.
+    ///
+    /// Fails if an object with that name already exists.
+    pub fn create(name: &str, capacity: usize) -> io::Result<Self> {
+        with_shm_name(name, |name| {
+            let fd = sys::shm_create(name)?;
+
+            Self::init(fd, capacity).inspect_err(|_| {
+                let _ = sys::shm_remove(name);
+            })
+        })
+    }
+
+    /// Creates a vector backed by an anonymous memory file, which other
+    /// processes reach through a duplicate of its descriptor, see
+    /// [`from_fd`](Self::from_fd).
+    #[cfg(target_os = "linux")]
+    pub fn create_anonymous(capacity: usize) -> io::Result<Self> {
+        let fd = sys::memfd(c"my-vec")?;
+        Self::init(fd, capacity)
+    }
+
+    /// Opens the vector created under `name` by [`create`](Self::create).
+    ///
+    /// # Safety
+    ///
+    /// Same as [`from_fd`](Self::from_fd).
+    pub unsafe fn open(name: &str) -> io::Result<Self> {
+        let fd = with_shm_name(name, sys::shm_open_existing)?;
+        Self::from_fd(fd)
+    }
+
+    /// Maps an existing vector through a descriptor of its memory object,
+    /// for example one a parent process passed on.
+    ///
+    /// Fails with [`io::ErrorKind::InvalidData`] if the object doesn't hold a
+    /// vector of `T`.
+    ///
+    /// # Safety
+    ///
+    /// Other handles on the same memory, in this process or another one,
+    /// must not write an element while any handle holds a slice (from
+    /// `Deref`) that covers it, and must not touch it at all while a handle
+    /// holds a mutable slice (from `DerefMut`, `push` or
+    /// `extend_from_slice`) covering it. The usual way to uphold this is a
+    /// single writer that only appends past the published length, with
+    /// readers that only look below it.
+    pub unsafe fn from_fd(fd: OwnedFd) -> io::Result<Self> {
+        let file_len = sys::file_len(fd.as_fd())?;
+        if file_len < mem::size_of::<Header>() {
+            return Err(io::ErrorKind::InvalidData.into());
+        }
+
+        let map = sys::map_shared(fd.as_fd(), file_len)?;
+        let header = unsafe { map.cast::<Header>().as_ref() };
+
+        let fits = match usize::try_from(header.capacity) {
+            Ok(capacity) => layout::<T>(capacity).is_some_and(|(size, _)| size <= file_len),
+            Err(_) => false,
+        };
+        if header.magic != MAGIC || header.elem_size != mem::size_of::<T>() as u64 || !fits {
+            unsafe { sys::unmap(map, file_len) };
+            return Err(io::ErrorKind::InvalidData.into());
+        }
+
+        let mut vec = Self::from_map(fd, map, file_len, header.capacity as usize);
+        vec.refresh();
+        Ok(vec)
+    }
+
+    /// Removes the name `name`; mappings that are already open stay valid.
+    pub fn unlink(name: &str) -> io::Result<()> {
+        with_shm_name(name, sys::shm_remove)
+    }
+
+    fn init(fd: OwnedFd, capacity: usize) -> io::Result<Self> {
+        let (map_len, _) = layout::<T>(capacity).ok_or(io::ErrorKind::InvalidInput)?;
+
+        sys::set_len(fd.as_fd(), map_len)?;
+        let map = sys::map_shared(fd.as_fd(), map_len)?;
+
+        unsafe {
+            map.cast::<Header>().write(Header {
+                magic: MAGIC,
+                elem_size: mem::size_of::<T>() as u64,
+                capacity: capacity as u64,
+                len: AtomicU64::new(0),
+            });
+        }
+
+        Ok(Self::from_map(fd, map, map_len, capacity))
+    }
+
+    fn from_map(fd: OwnedFd, map: NonNull<u8>, map_len: usize, capacity: usize) -> Self {
+        let (_, offset) = layout::<T>(capacity).unwrap();
+
+        Self {
+            fd,
+            map,
+            map_len,
+            data: unsafe { map.add(offset).cast() },
+            capacity,
+            len: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Appends `elem`, or hands it back if the vector is full.
+    ///
+    /// Other handles only see it after [`publish`](Self::publish).
+    pub fn push(&mut self, elem: T) -> Result<(), T> {
+        if self.len == self.capacity {
+            return Err(elem);
+        }
+
+        unsafe { self.data.add(self.len).write(elem) };
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Appends as many elements of `other` as fit, returning how many did.
+    pub fn extend_from_slice(&mut self, other: &[T]) -> usize {
+        let n = other.len().min(self.capacity - self.len);
+
+        unsafe {
+            self.data
+                .add(self.len)
+                .copy_from_nonoverlapping(NonNull::from(other).cast(), n);
+        }
+        self.len += n;
+
+        n
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        self.len = self.len.min(len);
+    }
+
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Makes this handle's length, and every element write before it,
+    /// visible to other handles.
+    pub fn publish(&self) {
+        self.header().len.store(self.len as u64, Ordering::Release);
+    }
+
+    /// Adopts the length last published by any handle, making the elements
+    /// written before it visible here.
+    pub fn refresh(&mut self) {
+        let published = self.header().len.load(Ordering::Acquire);
+        self.len = (published as usize).min(self.capacity);
+    }
+
+    /// Tells the kernel how the elements in `range` are about to be used.
+    ///
+    /// The memory is shared, so even [`Advice::DontNeed`] keeps the elements;
+    /// their pages are just fetched again on the next access.
+    pub fn advise(&self, range: Range<usize>, advice: Advice) {
+        assert!(
+            range.start <= range.end && range.end <= self.len,
+            "range out of bounds"
+        );
+
+        let size = mem::size_of::<T>();
+        if size == 0 || range.is_empty() {
+            return;
+        }
+
+        unsafe {
+            let start = self.data.add(range.start).cast();
+            sys::advise(start, (range.end - range.start) * size, advice);
+        }
+    }
+
+    fn header(&self) -> &Header {
+        unsafe { self.map.cast().as_ref() }
+    }
+}
+
+impl<T: Pod> Deref for ShmVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.data.as_ptr(), self.len) }
+    }
+}
+
+impl<T: Pod> DerefMut for ShmVec<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.data.as_ptr(), self.len) }
+    }
+}
+
+impl<T: Pod> AsFd for ShmVec<T> {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+}
+
+impl<T: Pod> Drop for ShmVec<T> {
+    fn drop(&mut self) {
+        unsafe { sys::unmap(self.map, self.map_len) };
+    }
+}
+
+unsafe impl<T: Pod + Send> Send for ShmVec<T> {}
+unsafe impl<T: Pod + Sync> Sync for ShmVec<T> {}
+
+/// Returns the size of the mapping for `capacity` elements, and the offset
+/// of the elements in it.
+fn layout<T>(capacity: usize) -> Option<(usize, usize)> {
+    let elems = Layout::array::<T>(capacity).ok()?;
+    let (layout, offset) = Layout::new::<Header>().extend(elems).ok()?;
+
+    Some((layout.size(), offset))
+}
+
+/// Passes `name` to `f` as a C string, NUL-terminated in a stack buffer so
+/// that nothing here allocates.
+fn with_shm_name<R>(name: &str, f: impl FnOnce(&CStr) -> io::Result<R>) -> io::Result<R> {
+    const NAME_MAX: usize = 255;
+
+    if name.len() > NAME_MAX || name.bytes().any(|b| b == 0) {
+        return Err(io::ErrorKind::InvalidInput.into());
+    }
+
+    let mut buf = [0; NAME_MAX + 1];
+    buf[..name.len()].copy_from_slice(name.as_bytes());
+
+    f(CStr::from_bytes_with_nul(&buf[..=name.len()]).unwrap())
+}
+
+#[test]
+fn shm_vec_shares_published_elements() {
+    let name = format!("/my-vec-test-{}", std::process::id());
+
+    let mut writer = ShmVec::<u32>::create(&name, 4).unwrap();
+    assert!(ShmVec::<u32>::create(&name, 4).is_err());
+    assert_eq!(
+        io::ErrorKind::InvalidInput,
+        unsafe { ShmVec::<u32>::open("/bad\0name") }
+            .err()
+            .unwrap()
+            .kind()
+    );
+    // SAFETY: the handles below are only ever used one at a time
+    let mut reader = unsafe { ShmVec::<u32>::open(&name) }.unwrap();
+    ShmVec::<u32>::unlink(&name).unwrap();
+    assert_eq!(4, reader.capacity());
+
+    writer.push(1).unwrap();
+    assert_eq!(3, writer.extend_from_slice(&[2, 3, 4, 5]));
+    assert_eq!(Err(6), writer.push(6));
+
+    reader.refresh();
+    assert!(reader.is_empty());
+    writer.publish();
+    reader.refresh();
+    assert_eq!(&[1, 2, 3, 4], &reader[..]);
+    reader.advise(0..4, Advice::DontNeed);
+    assert_eq!(&[1, 2, 3, 4], &reader[..]);
+
+    // both handles map the same pages
+    writer[0] = 10;
+    assert_eq!(10, reader[0]);
+
+    let other =
+        unsafe { ShmVec::<u32>::from_fd(writer.as_fd().try_clone_to_owned().unwrap()) }.unwrap();
+    assert_eq!(4, other.len());
+    let wrong = unsafe { ShmVec::<u64>::from_fd(writer.as_fd().try_clone_to_owned().unwrap()) };
+    assert_eq!(io::ErrorKind::InvalidData, wrong.err().unwrap().kind());
+
+    #[cfg(target_os = "linux")]
+    {
+        let mut anon = ShmVec::<u8>::create_anonymous(16).unwrap();
+        anon.push(7).unwrap();
+        anon.publish();
+        let fd = anon.as_fd().try_clone_to_owned().unwrap();
+        assert_eq!(&[7], &unsafe { ShmVec::<u8>::from_fd(fd) }.unwrap()[..]);
+    }
+}
@@ -0,0 +1,120 @@
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::mem;
+use std::path::Path;
+
+use crate::{MyVec, Pod};
+
+const MAGIC: [u8; 4] = *b"MYVC";
+const VERSION: u8 = 1;
+
+// 0 for little endian, 1 for big endian
+const NATIVE_ENDIAN: u8 = if cfg!(target_endian = "little") { 0 } else { 1 };
+
+// magic, version, endianness, element size (u32), length (u64)
+const HEADER_LEN: usize = 4 + 1 + 1 + 4 + 8;
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+impl<T: Pod> MyVec<T> {
+    /// Writes a binary snapshot: a small header recording the element size,
+    /// the byte order and the length, followed by the raw element bytes.
+    pub fn write_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let elem_size =
+            u32::try_from(mem::size_of::<T>()).map_err(|_| invalid_data("element too large"))?;
+
+        let mut header = [0u8; HEADER_LEN];
+        header[..4].copy_from_slice(&MAGIC);
+        header[4] = VERSION;
+        header[5] = NATIVE_ENDIAN;
+        header[6..10].copy_from_slice(&elem_size.to_le_bytes());
+        header[10..].copy_from_slice(&(self.len() as u64).to_le_bytes());
+
+        writer.write_all(&header)?;
+        writer.write_all(self.as_bytes())?;
+        writer.flush()
+    }
+
+    pub fn save_to<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.write_to(BufWriter::new(File::create(path)?))
+    }
+
+    /// Reads a snapshot written by [`write_to`](Self::write_to).
+    ///
+    /// Fails with [`io::ErrorKind::InvalidData`] if the snapshot was written
+    /// for a different element size or byte order, and with
+    /// [`io::ErrorKind::OutOfMemory`] if its length cannot be allocated.
+    pub fn read_from<R: Read>(mut reader: R) -> io::Result<Self> {
+        let mut header = [0u8; HEADER_LEN];
+        reader.read_exact(&mut header)?;
+
+        if header[..4] != MAGIC {
+            return Err(invalid_data("not a MyVec snapshot"));
+        }
+        if header[4] != VERSION {
+            return Err(invalid_data("unsupported snapshot version"));
+        }
+        if header[5] != NATIVE_ENDIAN {
+            return Err(invalid_data(
+                "snapshot byte order does not match this target",
+            ));
+        }
+
+        let elem_size = u32::from_le_bytes(header[6..10].try_into().unwrap());
+        if elem_size as usize != mem::size_of::<T>() {
+            return Err(invalid_data("snapshot element size does not match"));
+        }
+
+        let len = u64::from_le_bytes(header[10..].try_into().unwrap());
+        let len = usize::try_from(len).map_err(|_| invalid_data("snapshot too long"))?;
+
+        let mut v =
+            Self::try_zeroed(len).map_err(|err| io::Error::new(io::ErrorKind::OutOfMemory, err))?;
+        reader.read_exact(v.as_bytes_mut())?;
+
+        Ok(v)
+    }
+
+    pub fn load_from<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::read_from(BufReader::new(File::open(path)?))
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn snapshot_round_trip() {
+    let mut v = MyVec::new();
+    for i in 0..100u64 {
+        v.push(i * i);
+    }
+
+    let mut bytes = Vec::new();
+    v.write_to(&mut bytes).unwrap();
+    assert_eq!(HEADER_LEN + 800, bytes.len());
+
+    let loaded = MyVec::<u64>::read_from(&bytes[..]).unwrap();
+    assert_eq!(&v[..], &loaded[..]);
+
+    let err = MyVec::<u32>::read_from(&bytes[..]).err().unwrap();
+    assert_eq!(io::ErrorKind::InvalidData, err.kind());
+
+    let truncated = MyVec::<u64>::read_from(&bytes[..bytes.len() - 1])
+        .err()
+        .unwrap();
+    assert_eq!(io::ErrorKind::UnexpectedEof, truncated.kind());
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn snapshot_file() {
+    let path = std::env::temp_dir().join(format!("my-vec-snapshot-{}", std::process::id()));
+
+    let v = MyVec::<[f32; 3]>::zeroed(10);
+    v.save_to(&path).unwrap();
+    let loaded = MyVec::<[f32; 3]>::load_from(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(&v[..], &loaded[..]);
+}
@@ -0,0 +1,137 @@
+use crate::MyVec;
+
+impl MyVec<u8> {
+    /// Splits the bytes on every occurrence of `delimiter`, like
+    /// `slice::split`, including the empty piece after a trailing delimiter.
+    pub fn split_on(&self, delimiter: u8) -> SplitOn<'_> {
+        SplitOn {
+            rest: Some(self),
+            delimiter,
+        }
+    }
+
+    /// Splits the bytes into lines, like `str::lines`: lines end in `\n` or
+    /// `\r\n`, the terminator is not included, and a final terminator does
+    /// not produce an empty line.
+    pub fn split_lines(&self) -> Lines<'_> {
+        Lines { rest: self }
+    }
+
+    /// Like [`MyVec::split_on`], but copies each piece into its own vector.
+    #[cfg(not(no_global_oom_handling))]
+    pub fn split_on_owned(&self, delimiter: u8) -> impl Iterator<Item = MyVec<u8>> + '_ {
+        self.split_on(delimiter).map(|piece| {
+            let mut owned = MyVec::with_capacity(piece.len());
+            owned.extend_from_slice(piece);
+            owned
+        })
+    }
+}
+
+pub struct SplitOn<'a> {
+    rest: Option<&'a [u8]>,
+    delimiter: u8,
+}
+
+impl<'a> Iterator for SplitOn<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        let rest = self.rest?;
+
+        match memchr(self.delimiter, rest) {
+            Some(i) => {
+                self.rest = Some(&rest[i + 1..]);
+                Some(&rest[..i])
+            }
+            None => {
+                self.rest = None;
+                Some(rest)
+            }
+        }
+    }
+}
+
+pub struct Lines<'a> {
+    rest: &'a [u8],
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        let line = match memchr(b'\n', self.rest) {
+            Some(i) => {
+                let line = &self.rest[..i];
+                self.rest = &self.rest[i + 1..];
+                line
+            }
+            None => std::mem::take(&mut self.rest),
+        };
+
+        Some(line.strip_suffix(b"\r").unwrap_or(line))
+    }
+}
+
+const WORD: usize = std::mem::size_of::<u64>();
+const LO: u64 = u64::from_ne_bytes([0x01; WORD]);
+const HI: u64 = u64::from_ne_bytes([0x80; WORD]);
+
+/// Returns the index of the first `needle` in `haystack`, testing a word at
+/// a time.
+fn memchr(needle: u8, haystack: &[u8]) -> Option<usize> {
+    let repeated = LO * needle as u64;
+
+    let mut chunks = haystack.chunks_exact(WORD);
+    let mut offset = 0;
+
+    for chunk in &mut chunks {
+        let word = u64::from_ne_bytes(chunk.try_into().unwrap()) ^ repeated;
+        // sets the high bit of some byte iff `word` has a zero byte
+        if word.wrapping_sub(LO) & !word & HI != 0 {
+            break;
+        }
+        offset += WORD;
+    }
+
+    haystack[offset..]
+        .iter()
+        .position(|&b| b == needle)
+        .map(|i| offset + i)
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn split_on_delimiters() {
+    let mut v = MyVec::new();
+    v.extend_from_slice(b"GET /a\r\nHost: x\n\nbody with no newline at all..|");
+
+    let lines: Vec<_> = v.split_lines().collect();
+    assert_eq!(
+        vec![
+            &b"GET /a"[..],
+            b"Host: x",
+            b"",
+            b"body with no newline at all..|"
+        ],
+        lines
+    );
+
+    let pieces: Vec<_> = v.split_on(b'|').collect();
+    assert_eq!(2, pieces.len());
+    assert!(pieces[1].is_empty());
+
+    let owned: Vec<_> = v.split_on_owned(b' ').map(|p| p.len()).collect();
+    assert_eq!(vec![3, 9, 7, 4, 2, 7, 2, 6], owned);
+
+    for i in 0..40 {
+        let mut hay = MyVec::from_elem(b'a', 40);
+        hay[i] = b'\n';
+        assert_eq!(Some(i), memchr(b'\n', &hay));
+    }
+    assert_eq!(None, memchr(0, b"no zeros in here"));
+}
@@ -0,0 +1,60 @@
+use crate::MyVec;
+
+/// Allocation counters kept per vector by the `alloc-stats` feature.
+///
+/// Vectors whose `reallocs` or `bytes_copied` keep climbing are the ones
+/// worth pre-sizing with `with_capacity(max_len)`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// How many times an existing buffer was grown.
+    pub reallocs: usize,
+    /// Bytes of live elements moved to a larger buffer, assuming every
+    /// reallocation copies (the allocator may sometimes grow in place).
+    pub bytes_copied: usize,
+    /// The largest length the vector has reached.
+    pub max_len: usize,
+    /// The largest capacity the vector has had.
+    pub max_capacity: usize,
+}
+
+impl AllocStats {
+    pub(crate) fn record_growth(&mut self, old_cap: usize, new_cap: usize, live_bytes: usize) {
+        if old_cap != 0 {
+            self.reallocs += 1;
+            self.bytes_copied += live_bytes;
+        }
+        self.max_capacity = self.max_capacity.max(new_cap);
+    }
+}
+
+impl<T> MyVec<T> {
+    pub fn stats(&self) -> AllocStats {
+        self.buf.stats
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn stats_track_growth() {
+    let mut v = MyVec::new();
+    for i in 0..10u32 {
+        v.push(i);
+    }
+    while v.len() > 2 {
+        v.pop();
+    }
+
+    // capacity went 1, 2, 4, 8, 16, copying 1 + 2 + 4 + 8 elements
+    let stats = v.stats();
+    assert_eq!(4, stats.reallocs);
+    assert_eq!(15 * 4, stats.bytes_copied);
+    assert_eq!(10, stats.max_len);
+    assert_eq!(16, stats.max_capacity);
+
+    let mut sized = MyVec::with_capacity(10);
+    for i in 0..10u32 {
+        sized.push(i);
+    }
+    assert_eq!(0, sized.stats().reallocs);
+    assert_eq!(10, sized.stats().max_capacity);
+}
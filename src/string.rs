@@ -0,0 +1,126 @@
+use std::error::Error;
+use std::fmt;
+use std::mem::ManuallyDrop;
+use std::ptr::NonNull;
+use std::str::{self, Utf8Error};
+
+use crate::{MyVec, RawVec};
+
+impl From<String> for MyVec<u8> {
+    /// Takes over the string's buffer without copying it.
+    fn from(s: String) -> Self {
+        let mut bytes = ManuallyDrop::new(s.into_bytes());
+
+        MyVec {
+            // `Vec` and `RawVec` both allocate `[u8; cap]` from the global
+            // allocator, and both use a dangling pointer when `cap == 0`
+            buf: unsafe {
+                RawVec::from_raw_parts(NonNull::new_unchecked(bytes.as_mut_ptr()), bytes.capacity())
+            },
+            len: bytes.len(),
+        }
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+impl From<&str> for MyVec<u8> {
+    fn from(s: &str) -> Self {
+        let mut v = MyVec::with_capacity(s.len());
+        v.extend_from_slice(s.as_bytes());
+        v
+    }
+}
+
+impl MyVec<u8> {
+    /// Converts the vector into a `String` without copying, or hands it back
+    /// inside the error if it is not valid UTF-8.
+    ///
+    /// Once the buffer belongs to the `String` it is no longer counted
+    /// against any attached memory budget.
+    pub fn from_utf8(self) -> Result<String, FromUtf8Error> {
+        match str::from_utf8(&self) {
+            Ok(_) => Ok(unsafe { String::from_utf8_unchecked(self.into_std_vec()) }),
+            Err(error) => Err(FromUtf8Error { bytes: self, error }),
+        }
+    }
+
+    /// Like [`MyVec::from_utf8`], but replaces invalid sequences with
+    /// U+FFFD; only then is a new buffer allocated.
+    #[cfg(not(no_global_oom_handling))]
+    pub fn from_utf8_lossy(self) -> String {
+        self.from_utf8()
+            .unwrap_or_else(|err| String::from_utf8_lossy(&err.bytes).into_owned())
+    }
+
+    fn into_std_vec(self) -> Vec<u8> {
+        let mut this = ManuallyDrop::new(self);
+
+        #[cfg(feature = "memory-budget")]
+        this.detach_budget();
+
+        unsafe { Vec::from_raw_parts(this.as_mut_ptr(), this.len, this.cap()) }
+    }
+}
+
+/// The error returned by [`MyVec::from_utf8`], holding on to the rejected
+/// bytes.
+pub struct FromUtf8Error {
+    bytes: MyVec<u8>,
+    error: Utf8Error,
+}
+
+impl FromUtf8Error {
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn into_bytes(self) -> MyVec<u8> {
+        self.bytes
+    }
+
+    pub fn utf8_error(&self) -> Utf8Error {
+        self.error
+    }
+}
+
+impl fmt::Debug for FromUtf8Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FromUtf8Error")
+            .field("bytes", &&self.bytes[..])
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+impl fmt::Display for FromUtf8Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl Error for FromUtf8Error {}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn string_round_trip_reuses_buffer() {
+    let mut s = String::with_capacity(32);
+    s.push_str("héllo");
+    let ptr = s.as_ptr();
+
+    let mut v = MyVec::from(s);
+    assert_eq!(ptr, v.as_ptr());
+    assert_eq!(32, v.capacity());
+    v.push(b'!');
+
+    let s = v.from_utf8().unwrap();
+    assert_eq!("héllo!", s);
+    assert_eq!(ptr, s.as_ptr());
+
+    let mut bad = MyVec::from("ok");
+    bad.push(0xff);
+    let err = bad.from_utf8().unwrap_err();
+    assert_eq!(2, err.utf8_error().valid_up_to());
+    assert_eq!("ok\u{fffd}", err.into_bytes().from_utf8_lossy());
+
+    assert!(MyVec::from(String::new()).from_utf8().unwrap().is_empty());
+}
@@ -0,0 +1,271 @@
+//! The handful of virtual memory and shared memory calls the mapping-backed
+//! containers need, declared by hand so the crate stays free of
+//! dependencies.
+//!
+//! Flag values differ between architectures on Linux (mips, powerpc, sparc
+//! and alpha all have their own), so the module is only built for the
+//! targets whose values are listed here, which use the generic ones.
+
+use std::ffi::CStr;
+use std::io;
+use std::os::fd::{AsRawFd, BorrowedFd, FromRawFd, OwnedFd};
+use std::os::raw::{c_char, c_int, c_long, c_void};
+use std::ptr::{self, NonNull};
+
+const PROT_NONE: c_int = 0;
+const PROT_READ: c_int = 1;
+const PROT_WRITE: c_int = 2;
+
+const MAP_SHARED: c_int = 0x01;
+const MAP_PRIVATE: c_int = 0x02;
+const MAP_FIXED: c_int = 0x10;
+#[cfg(target_os = "linux")]
+const MAP_ANONYMOUS: c_int = 0x20;
+#[cfg(target_os = "macos")]
+const MAP_ANONYMOUS: c_int = 0x1000;
+#[cfg(target_os = "linux")]
+const MAP_NORESERVE: c_int = 0x4000;
+#[cfg(target_os = "macos")]
+const MAP_NORESERVE: c_int = 0x40;
+
+// like the mmap flags above, these are the generic values; mips and
+// friends, where `O_CREAT` and `O_EXCL` differ, don't build this module
+const O_RDWR: c_int = 2;
+#[cfg(target_os = "linux")]
+const O_CREAT: c_int = 0o100;
+#[cfg(target_os = "macos")]
+const O_CREAT: c_int = 0x200;
+#[cfg(target_os = "linux")]
+const O_EXCL: c_int = 0o200;
+#[cfg(target_os = "macos")]
+const O_EXCL: c_int = 0x800;
+
+const SEEK_END: c_int = 2;
+
+const MADV_SEQUENTIAL: c_int = 2;
+const MADV_WILLNEED: c_int = 3;
+const MADV_DONTNEED: c_int = 4;
+
+#[cfg(target_os = "linux")]
+const MFD_CLOEXEC: u32 = 1;
+
+#[cfg(target_os = "linux")]
+const SC_PAGESIZE: c_int = 30;
+#[cfg(target_os = "macos")]
+const SC_PAGESIZE: c_int = 29;
+
+// `off_t` is 64 bits everywhere this module builds except 32-bit glibc,
+// which has the 64-bit calls under their own names
+#[allow(non_camel_case_types)]
+type off_t = i64;
+
+extern "C" {
+    #[cfg_attr(
+        all(target_os = "linux", target_env = "gnu", target_pointer_width = "32"),
+        link_name = "mmap64"
+    )]
+    fn mmap(
+        addr: *mut c_void,
+        len: usize,
+        prot: c_int,
+        flags: c_int,
+        fd: c_int,
+        offset: off_t,
+    ) -> *mut c_void;
+    fn mprotect(addr: *mut c_void, len: usize, prot: c_int) -> c_int;
+    fn munmap(addr: *mut c_void, len: usize) -> c_int;
+    fn madvise(addr: *mut c_void, len: usize, advice: c_int) -> c_int;
+    fn sysconf(name: c_int) -> c_long;
+    #[cfg_attr(
+        all(target_os = "linux", target_env = "gnu", target_pointer_width = "32"),
+        link_name = "ftruncate64"
+    )]
+    fn ftruncate(fd: c_int, len: off_t) -> c_int;
+    #[cfg_attr(
+        all(target_os = "linux", target_env = "gnu", target_pointer_width = "32"),
+        link_name = "lseek64"
+    )]
+    fn lseek(fd: c_int, offset: off_t, whence: c_int) -> off_t;
+    fn shm_unlink(name: *const c_char) -> c_int;
+    #[cfg(target_os = "linux")]
+    fn shm_open(name: *const c_char, oflag: c_int, mode: u32) -> c_int;
+    #[cfg(target_os = "macos")]
+    fn shm_open(name: *const c_char, oflag: c_int, ...) -> c_int;
+    #[cfg(target_os = "linux")]
+    fn memfd_create(name: *const c_char, flags: u32) -> c_int;
+}
+
+/// How a range of a mapping-backed vector is about to be used, passed on to
+/// the kernel as `madvise` advice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Advice {
+    /// The range will be read soon, so start paging it in.
+    WillNeed,
+    /// The range won't be read for a while, so its pages can be reclaimed
+    /// first.
+    DontNeed,
+    /// The range will be read front to back, so read ahead aggressively.
+    Sequential,
+}
+
+fn check_fd(fd: c_int) -> io::Result<OwnedFd> {
+    if fd < 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(unsafe { OwnedFd::from_raw_fd(fd) })
+    }
+}
+
+fn map_failed(addr: *mut c_void) -> bool {
+    addr.addr() == usize::MAX
+}
+
+pub(crate) fn page_size() -> usize {
+    unsafe { sysconf(SC_PAGESIZE) as usize }
+}
+
+/// Reserves `len` bytes of address space without backing any of it.
+pub(crate) fn reserve(len: usize) -> Option<NonNull<u8>> {
+    let addr = unsafe {
+        mmap(
+            ptr::null_mut(),
+            len,
+            PROT_NONE,
+            MAP_PRIVATE | MAP_ANONYMOUS | MAP_NORESERVE,
+            -1,
+            0,
+        )
+    };
+
+    if map_failed(addr) {
+        None
+    } else {
+        NonNull::new(addr.cast())
+    }
+}
+
+/// Makes `len` bytes at `addr` readable and writable.
+///
+/// # Safety
+///
+/// The range must lie inside a mapping returned by [`reserve`].
+pub(crate) unsafe fn commit(addr: NonNull<u8>, len: usize) -> bool {
+    mprotect(addr.as_ptr().cast(), len, PROT_READ | PROT_WRITE) == 0
+}
+
+/// Hands the pages of a committed range back to the OS, leaving it reserved.
+///
+/// # Safety
+///
+/// The range must lie inside a mapping returned by [`reserve`], and nothing
+/// may still point into it.
+pub(crate) unsafe fn decommit(addr: NonNull<u8>, len: usize) -> bool {
+    // mapping fresh inaccessible pages over the range drops the old ones on
+    // every platform, which madvise does not guarantee
+    let fresh = mmap(
+        addr.as_ptr().cast(),
+        len,
+        PROT_NONE,
+        MAP_PRIVATE | MAP_ANONYMOUS | MAP_NORESERVE | MAP_FIXED,
+        -1,
+        0,
+    );
+
+    !map_failed(fresh)
+}
+
+/// Passes `advice` on for the `len` bytes at `addr`, widened to whole pages.
+/// Advice is only a hint, so failures are ignored.
+///
+/// # Safety
+///
+/// The range must lie inside a live mapping, and `advice` must not be
+/// [`Advice::DontNeed`] on a private mapping, where it discards the data.
+pub(crate) unsafe fn advise(addr: NonNull<u8>, len: usize, advice: Advice) {
+    let advice = match advice {
+        Advice::WillNeed => MADV_WILLNEED,
+        Advice::DontNeed => MADV_DONTNEED,
+        Advice::Sequential => MADV_SEQUENTIAL,
+    };
+    let offset = addr.addr().get() % page_size();
+
+    madvise(
+        addr.as_ptr().wrapping_byte_sub(offset).cast(),
+        len + offset,
+        advice,
+    );
+}
+
+/// # Safety
+///
+/// `addr` and `len` must describe a whole mapping, which must not be used
+/// again.
+pub(crate) unsafe fn unmap(addr: NonNull<u8>, len: usize) {
+    munmap(addr.as_ptr().cast(), len);
+}
+
+/// Creates the shared memory object `name`, failing if it already exists.
+pub(crate) fn shm_create(name: &CStr) -> io::Result<OwnedFd> {
+    check_fd(unsafe { shm_open(name.as_ptr(), O_RDWR | O_CREAT | O_EXCL, 0o600) })
+}
+
+pub(crate) fn shm_open_existing(name: &CStr) -> io::Result<OwnedFd> {
+    check_fd(unsafe { shm_open(name.as_ptr(), O_RDWR, 0) })
+}
+
+pub(crate) fn shm_remove(name: &CStr) -> io::Result<()> {
+    if unsafe { shm_unlink(name.as_ptr()) } == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+/// Creates an anonymous shared memory file, shareable only by passing the
+/// descriptor on.
+#[cfg(target_os = "linux")]
+pub(crate) fn memfd(name: &CStr) -> io::Result<OwnedFd> {
+    check_fd(unsafe { memfd_create(name.as_ptr(), MFD_CLOEXEC) })
+}
+
+pub(crate) fn set_len(fd: BorrowedFd<'_>, len: usize) -> io::Result<()> {
+    let len = off_t::try_from(len).map_err(|_| io::ErrorKind::InvalidInput)?;
+
+    if unsafe { ftruncate(fd.as_raw_fd(), len) } == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+pub(crate) fn file_len(fd: BorrowedFd<'_>) -> io::Result<usize> {
+    let len = unsafe { lseek(fd.as_raw_fd(), 0, SEEK_END) };
+
+    if len < 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        // a file larger than the address space can't be mapped anyway
+        usize::try_from(len).map_err(|_| io::ErrorKind::InvalidData.into())
+    }
+}
+
+/// Maps the first `len` bytes of `fd` readable and writable, shared with
+/// every other mapping of it.
+pub(crate) fn map_shared(fd: BorrowedFd<'_>, len: usize) -> io::Result<NonNull<u8>> {
+    let addr = unsafe {
+        mmap(
+            ptr::null_mut(),
+            len,
+            PROT_READ | PROT_WRITE,
+            MAP_SHARED,
+            fd.as_raw_fd(),
+            0,
+        )
+    };
+
+    if map_failed(addr) {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(NonNull::new(addr.cast()).unwrap())
+    }
+}
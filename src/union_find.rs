@@ -0,0 +1,125 @@
+use crate::MyVec;
+
+/// A disjoint-set forest over the indices `0..len`, with path compression and
+/// union by rank.
+pub struct UnionFind {
+    parents: MyVec<usize>,
+    ranks: MyVec<u8>,
+    sets: usize,
+}
+
+impl UnionFind {
+    /// Creates `len` singleton sets.
+    pub fn new(len: usize) -> Self {
+        let mut parents = MyVec::with_capacity(len);
+        let mut ranks = MyVec::with_capacity(len);
+
+        for i in 0..len {
+            parents.push(i);
+            ranks.push(0);
+        }
+
+        Self {
+            parents,
+            ranks,
+            sets: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Returns the number of disjoint sets.
+    pub fn set_count(&self) -> usize {
+        self.sets
+    }
+
+    /// Returns the representative of the set containing `x`.
+    pub fn find(&mut self, x: usize) -> usize {
+        assert!(x < self.len(), "index out of bounds");
+
+        let mut root = x;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        let mut node = x;
+        while self.parents[node] != root {
+            let next = self.parents[node];
+            self.parents[node] = root;
+            node = next;
+        }
+
+        root
+    }
+
+    /// Merges the sets containing `a` and `b`, returning `false` if they were
+    /// already the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        let (child, parent) = if self.ranks[a] < self.ranks[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+
+        self.parents[child] = parent;
+        if self.ranks[child] == self.ranks[parent] {
+            self.ranks[parent] += 1;
+        }
+        self.sets -= 1;
+
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Returns every set as a list of its members in ascending order, the sets
+    /// ordered by their smallest member.
+    pub fn groups(&mut self) -> MyVec<MyVec<usize>> {
+        // maps each root to the position of its group in the output
+        let mut slots = MyVec::with_capacity(self.len());
+        for _ in 0..self.len() {
+            slots.push(usize::MAX);
+        }
+
+        let mut groups: MyVec<MyVec<usize>> = MyVec::with_capacity(self.sets);
+        for x in 0..self.len() {
+            let root = self.find(x);
+            if slots[root] == usize::MAX {
+                slots[root] = groups.len();
+                groups.push(MyVec::new());
+            }
+            groups[slots[root]].push(x);
+        }
+
+        groups
+    }
+}
+
+#[test]
+fn union_find_groups() {
+    let mut uf = UnionFind::new(6);
+    assert!(uf.union(0, 3));
+    assert!(uf.union(4, 5));
+    assert!(uf.union(3, 5));
+    assert!(!uf.union(0, 4));
+    assert!(uf.connected(0, 5));
+    assert!(!uf.connected(1, 2));
+    assert_eq!(3, uf.set_count());
+
+    let groups = uf.groups();
+    let groups: Vec<&[usize]> = groups.iter().map(|g| &g[..]).collect();
+    assert_eq!(vec![&[0, 3, 4, 5][..], &[1], &[2]], groups);
+}
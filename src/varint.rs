@@ -0,0 +1,120 @@
+use std::error::Error;
+use std::fmt;
+
+#[cfg(not(no_global_oom_handling))]
+use crate::MyVec;
+
+/// The longest LEB128 encoding of a `u64`.
+const MAX_VARINT_LEN: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VarintError {
+    /// The input ended before the final byte of the varint.
+    Truncated,
+    /// The encoded value does not fit in 64 bits.
+    Overflow,
+}
+
+impl fmt::Display for VarintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VarintError::Truncated => f.write_str("varint is truncated"),
+            VarintError::Overflow => f.write_str("varint overflows 64 bits"),
+        }
+    }
+}
+
+impl Error for VarintError {}
+
+#[cfg(not(no_global_oom_handling))]
+impl MyVec<u8> {
+    /// Appends `value` as an unsigned LEB128 varint.
+    pub fn put_varint_u64(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+
+        self.push(value as u8);
+    }
+
+    /// Appends `value` as a zigzag-encoded LEB128 varint, so that small
+    /// negative numbers stay short.
+    pub fn put_varint_i64(&mut self, value: i64) {
+        self.put_varint_u64(((value << 1) ^ (value >> 63)) as u64);
+    }
+}
+
+/// Decodes an unsigned LEB128 varint from the start of `buf`, returning the
+/// value and the number of bytes consumed.
+pub fn get_varint_u64(buf: &[u8]) -> Result<(u64, usize), VarintError> {
+    let mut value = 0u64;
+
+    for (i, &byte) in buf.iter().take(MAX_VARINT_LEN).enumerate() {
+        // the tenth byte only has room for the top bit of a u64
+        if i == MAX_VARINT_LEN - 1 && byte > 1 {
+            return Err(VarintError::Overflow);
+        }
+
+        value |= u64::from(byte & 0x7f) << (7 * i);
+
+        if byte & 0x80 == 0 {
+            return Ok((value, i + 1));
+        }
+    }
+
+    if buf.len() < MAX_VARINT_LEN {
+        Err(VarintError::Truncated)
+    } else {
+        Err(VarintError::Overflow)
+    }
+}
+
+/// Decodes a zigzag-encoded LEB128 varint from the start of `buf`, returning
+/// the value and the number of bytes consumed.
+pub fn get_varint_i64(buf: &[u8]) -> Result<(i64, usize), VarintError> {
+    let (raw, len) = get_varint_u64(buf)?;
+
+    Ok((((raw >> 1) as i64) ^ -((raw & 1) as i64), len))
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn varint_round_trip() {
+    let values = [0, 1, 127, 128, 300, u32::MAX as u64, u64::MAX];
+
+    let mut buf = MyVec::new();
+    for &v in &values {
+        buf.put_varint_u64(v);
+    }
+
+    let mut rest = &buf[..];
+    for &v in &values {
+        let (decoded, len) = get_varint_u64(rest).unwrap();
+        assert_eq!(v, decoded);
+        rest = &rest[len..];
+    }
+    assert!(rest.is_empty());
+
+    let mut buf = MyVec::new();
+    buf.put_varint_i64(-1);
+    assert_eq!(1, buf.len());
+    buf.put_varint_i64(i64::MIN);
+    buf.put_varint_i64(i64::MAX);
+
+    let (a, len_a) = get_varint_i64(&buf).unwrap();
+    let (b, len_b) = get_varint_i64(&buf[len_a..]).unwrap();
+    let (c, _) = get_varint_i64(&buf[len_a + len_b..]).unwrap();
+    assert_eq!((-1, i64::MIN, i64::MAX), (a, b, c));
+}
+
+#[test]
+fn varint_errors() {
+    assert_eq!(Err(VarintError::Truncated), get_varint_u64(&[]));
+    assert_eq!(Err(VarintError::Truncated), get_varint_u64(&[0x80, 0x80]));
+    assert_eq!(Err(VarintError::Overflow), get_varint_u64(&[0xff; 10]));
+    assert_eq!(
+        Err(VarintError::Overflow),
+        get_varint_u64(&[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02])
+    );
+}
@@ -0,0 +1,279 @@
+use crate::MyVec;
+
+const NIL: usize = usize::MAX;
+
+/// A stable reference to an element of a [`VecList`].
+///
+/// Handles stay valid until their element is removed; a handle to a removed
+/// element is never confused with whatever later reuses its slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Handle {
+    index: usize,
+    generation: u32,
+}
+
+struct Node<T> {
+    value: Option<T>,
+    prev: usize,
+    next: usize, // doubles as the free list link for vacant nodes
+    generation: u32,
+}
+
+/// A doubly linked list whose nodes live in a `MyVec` slab and link to each
+/// other by index.
+///
+/// Removed nodes go on a free list and are reused by later insertions, so a
+/// list that stays around the same size stops allocating.
+pub struct VecList<T> {
+    nodes: MyVec<Node<T>>,
+    head: usize,
+    tail: usize,
+    free: usize,
+    len: usize,
+}
+
+impl<T> VecList<T> {
+    pub fn new() -> Self {
+        Self {
+            nodes: MyVec::new(),
+            head: NIL,
+            tail: NIL,
+            free: NIL,
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn front(&self) -> Option<Handle> {
+        self.handle(self.head)
+    }
+
+    pub fn back(&self) -> Option<Handle> {
+        self.handle(self.tail)
+    }
+
+    /// Returns the handle of the element after `handle`.
+    pub fn next(&self, handle: Handle) -> Option<Handle> {
+        self.handle(self.node(handle)?.next)
+    }
+
+    /// Returns the handle of the element before `handle`.
+    pub fn prev(&self, handle: Handle) -> Option<Handle> {
+        self.handle(self.node(handle)?.prev)
+    }
+
+    pub fn get(&self, handle: Handle) -> Option<&T> {
+        self.node(handle)?.value.as_ref()
+    }
+
+    pub fn get_mut(&mut self, handle: Handle) -> Option<&mut T> {
+        let node = self.nodes.get_mut(handle.index)?;
+        if node.generation == handle.generation {
+            node.value.as_mut()
+        } else {
+            None
+        }
+    }
+
+    pub fn push_front(&mut self, value: T) -> Handle {
+        let head = self.head;
+        self.link(value, NIL, head)
+    }
+
+    pub fn push_back(&mut self, value: T) -> Handle {
+        let tail = self.tail;
+        self.link(value, tail, NIL)
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let handle = self.front()?;
+        self.remove(handle)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let handle = self.back()?;
+        self.remove(handle)
+    }
+
+    /// Inserts `value` right before `handle`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `handle` refers to a removed element.
+    pub fn insert_before(&mut self, handle: Handle, value: T) -> Handle {
+        let prev = self.node(handle).expect("stale handle").prev;
+        self.link(value, prev, handle.index)
+    }
+
+    /// Inserts `value` right after `handle`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `handle` refers to a removed element.
+    pub fn insert_after(&mut self, handle: Handle, value: T) -> Handle {
+        let next = self.node(handle).expect("stale handle").next;
+        self.link(value, handle.index, next)
+    }
+
+    /// Removes the element at `handle`, or returns `None` if it was already
+    /// removed.
+    pub fn remove(&mut self, handle: Handle) -> Option<T> {
+        let (prev, next) = {
+            let node = self.node(handle)?;
+            (node.prev, node.next)
+        };
+
+        if prev == NIL {
+            self.head = next;
+        } else {
+            self.nodes[prev].next = next;
+        }
+        if next == NIL {
+            self.tail = prev;
+        } else {
+            self.nodes[next].prev = prev;
+        }
+
+        let node = &mut self.nodes[handle.index];
+        let value = node.value.take();
+        node.generation = node.generation.wrapping_add(1);
+        node.next = self.free;
+        self.free = handle.index;
+        self.len -= 1;
+
+        value
+    }
+
+    pub fn iter(&self) -> VecListIter<'_, T> {
+        VecListIter {
+            list: self,
+            next: self.head,
+            remaining: self.len,
+        }
+    }
+
+    fn node(&self, handle: Handle) -> Option<&Node<T>> {
+        self.nodes
+            .get(handle.index)
+            .filter(|node| node.generation == handle.generation && node.value.is_some())
+    }
+
+    fn handle(&self, index: usize) -> Option<Handle> {
+        if index == NIL {
+            None
+        } else {
+            Some(Handle {
+                index,
+                generation: self.nodes[index].generation,
+            })
+        }
+    }
+
+    fn link(&mut self, value: T, prev: usize, next: usize) -> Handle {
+        let index = if self.free == NIL {
+            self.nodes.push(Node {
+                value: Some(value),
+                prev,
+                next,
+                generation: 0,
+            });
+            self.nodes.len() - 1
+        } else {
+            let index = self.free;
+            let node = &mut self.nodes[index];
+            self.free = node.next;
+            node.value = Some(value);
+            node.prev = prev;
+            node.next = next;
+            index
+        };
+
+        if prev == NIL {
+            self.head = index;
+        } else {
+            self.nodes[prev].next = index;
+        }
+        if next == NIL {
+            self.tail = index;
+        } else {
+            self.nodes[next].prev = index;
+        }
+        self.len += 1;
+
+        Handle {
+            index,
+            generation: self.nodes[index].generation,
+        }
+    }
+}
+
+impl<T> Default for VecList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct VecListIter<'a, T> {
+    list: &'a VecList<T>,
+    next: usize,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for VecListIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == NIL {
+            None
+        } else {
+            let node = &self.list.nodes[self.next];
+            self.next = node.next;
+            self.remaining -= 1;
+            node.value.as_ref()
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+#[test]
+fn vec_list_handles() {
+    let mut list = VecList::new();
+    let b = list.push_back("b");
+    let a = list.push_front("a");
+    let d = list.push_back("d");
+    let c = list.insert_after(b, "c");
+    assert_eq!(
+        vec!["a", "b", "c", "d"],
+        list.iter().copied().collect::<Vec<_>>()
+    );
+
+    assert_eq!(Some("b"), list.remove(b));
+    assert_eq!(None, list.remove(b));
+    assert_eq!(None, list.get(b));
+
+    // the freed slot is reused without reviving the old handle
+    let e = list.insert_before(a, "e");
+    assert_eq!(None, list.get(b));
+    assert_eq!(Some(&"e"), list.get(e));
+    assert_eq!(Some(c), list.next(a));
+    assert_eq!(Some(a), list.prev(c));
+
+    *list.get_mut(d).unwrap() = "z";
+    assert_eq!(
+        vec!["e", "a", "c", "z"],
+        list.iter().copied().collect::<Vec<_>>()
+    );
+    assert_eq!(Some("e"), list.pop_front());
+    assert_eq!(Some("z"), list.pop_back());
+    assert_eq!(2, list.len());
+}
@@ -0,0 +1,257 @@
+use std::alloc::Layout;
+use std::mem;
+use std::ops::{Deref, DerefMut, Range};
+use std::ptr::{self, NonNull};
+use std::slice;
+
+use crate::{sys, Advice, TryReserveError};
+
+/// A vector with a fixed maximum capacity whose address range is reserved up
+/// front and backed by memory page by page as it grows.
+///
+/// Growing never moves the elements, so pushes have no reallocation spikes
+/// and pointers into the vector stay valid until the element is removed.
+/// Reserving is cheap even for very large maximums: only committed pages
+/// count against the process's memory.
+pub struct VirtualVec<T> {
+    ptr: NonNull<T>,
+    len: usize,
+    max_cap: usize,
+    page: usize,
+    reserved: usize,  // bytes of address space mapped
+    committed: usize, // bytes at the start of it that are accessible
+}
+
+impl<T> VirtualVec<T> {
+    #[cfg(not(no_global_oom_handling))]
+    pub fn with_max_capacity(max_cap: usize) -> Self {
+        Self::try_with_max_capacity(max_cap).unwrap_or_else(|err| err.handle())
+    }
+
+    /// Reserves address space for `max_cap` elements without committing any
+    /// of it.
+    pub fn try_with_max_capacity(max_cap: usize) -> Result<Self, TryReserveError> {
+        let page = sys::page_size();
+        let mut vec = Self {
+            ptr: NonNull::dangling(),
+            len: 0,
+            max_cap,
+            page,
+            reserved: 0,
+            committed: 0,
+        };
+
+        if mem::size_of::<T>() == 0 {
+            vec.max_cap = usize::MAX;
+            return Ok(vec);
+        }
+
+        let layout = crate::array_layout::<T>(max_cap)?;
+        let reserved = layout
+            .size()
+            .checked_next_multiple_of(page)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+
+        if reserved == 0 {
+            return Ok(vec);
+        }
+        if layout.align() > page {
+            return Err(TryReserveError::AllocError { layout });
+        }
+
+        vec.ptr = sys::reserve(reserved)
+            .ok_or(TryReserveError::AllocError { layout })?
+            .cast();
+        vec.reserved = reserved;
+
+        Ok(vec)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the most elements the vector can ever hold.
+    pub fn max_capacity(&self) -> usize {
+        self.max_cap
+    }
+
+    /// Returns how many bytes of the reservation are currently backed by
+    /// memory.
+    pub fn committed_bytes(&self) -> usize {
+        self.committed
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the vector is already at its maximum capacity.
+    #[cfg(not(no_global_oom_handling))]
+    pub fn push(&mut self, elem: T) {
+        assert!(self.len < self.max_cap, "VirtualVec is full");
+
+        self.try_push(elem).unwrap_or_else(|err| err.handle())
+    }
+
+    /// Like [`push`](Self::push), but fails with
+    /// [`TryReserveError::CapacityOverflow`] when the vector is full, and
+    /// with [`TryReserveError::AllocError`] if the OS refuses to commit more
+    /// pages.
+    pub fn try_push(&mut self, elem: T) -> Result<(), TryReserveError> {
+        if self.len == self.max_cap {
+            return Err(TryReserveError::CapacityOverflow);
+        }
+
+        self.commit_for(self.len + 1)?;
+
+        unsafe {
+            ptr::write(self.ptr.as_ptr().add(self.len), elem);
+        }
+        self.len += 1;
+
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            self.len -= 1;
+            unsafe { Some(ptr::read(self.ptr.as_ptr().add(self.len))) }
+        }
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            let tail = ptr::slice_from_raw_parts_mut(
+                unsafe { self.ptr.as_ptr().add(len) },
+                self.len - len,
+            );
+            self.len = len;
+            unsafe { ptr::drop_in_place(tail) };
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Returns the committed pages past the last element to the OS.
+    pub fn shrink_to_fit(&mut self) {
+        let keep = (self.len * mem::size_of::<T>()).next_multiple_of(self.page);
+
+        if keep < self.committed {
+            let start = unsafe { self.ptr.cast::<u8>().add(keep) };
+
+            if unsafe { sys::decommit(start, self.committed - keep) } {
+                self.committed = keep;
+            }
+        }
+    }
+
+    /// Tells the kernel how the elements in `range` are about to be used.
+    ///
+    /// [`Advice::DontNeed`] is ignored: on this private mapping the kernel
+    /// would throw the elements away rather than just page them out.
+    pub fn advise(&self, range: Range<usize>, advice: Advice) {
+        assert!(
+            range.start <= range.end && range.end <= self.len,
+            "range out of bounds"
+        );
+
+        let size = mem::size_of::<T>();
+        if size == 0 || range.is_empty() || advice == Advice::DontNeed {
+            return;
+        }
+
+        unsafe {
+            let start = self.ptr.add(range.start).cast();
+            sys::advise(start, (range.end - range.start) * size, advice);
+        }
+    }
+
+    /// Makes sure the first `len` elements are backed by memory, committing
+    /// at least as much again as is committed already to keep the number of
+    /// calls into the OS logarithmic.
+    fn commit_for(&mut self, len: usize) -> Result<(), TryReserveError> {
+        let needed = len * mem::size_of::<T>();
+        if needed <= self.committed {
+            return Ok(());
+        }
+
+        let target = needed
+            .max(self.committed * 2)
+            .next_multiple_of(self.page)
+            .min(self.reserved);
+        let start = unsafe { self.ptr.cast::<u8>().add(self.committed) };
+
+        if unsafe { sys::commit(start, target - self.committed) } {
+            self.committed = target;
+            Ok(())
+        } else {
+            Err(TryReserveError::AllocError {
+                layout: Layout::from_size_align(target, self.page).unwrap(),
+            })
+        }
+    }
+}
+
+impl<T> Deref for VirtualVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T> DerefMut for VirtualVec<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T> Drop for VirtualVec<T> {
+    fn drop(&mut self) {
+        self.clear();
+
+        if self.reserved != 0 {
+            unsafe { sys::unmap(self.ptr.cast(), self.reserved) };
+        }
+    }
+}
+
+unsafe impl<T: Send> Send for VirtualVec<T> {}
+unsafe impl<T: Sync> Sync for VirtualVec<T> {}
+
+#[test]
+fn virtual_vec_grows_in_place() {
+    let mut v = VirtualVec::<u64>::try_with_max_capacity(1 << 24).unwrap();
+    assert_eq!(0, v.committed_bytes());
+
+    v.try_push(0).unwrap();
+    let first = v.as_ptr();
+    for i in 1..100_000 {
+        v.try_push(i).unwrap();
+    }
+    assert_eq!(first, v.as_ptr());
+    assert!(v.committed_bytes() >= 100_000 * 8);
+    assert!(v.committed_bytes() < 1 << 27);
+    assert!(v.iter().copied().eq(0..100_000));
+    v.advise(0..100_000, Advice::Sequential);
+    v.advise(50_000..50_001, Advice::DontNeed);
+    assert_eq!(50_000, v[50_000]);
+
+    v.truncate(10);
+    v.shrink_to_fit();
+    assert_eq!(sys::page_size(), v.committed_bytes());
+    assert_eq!(Some(9), v.pop());
+
+    let mut full = VirtualVec::try_with_max_capacity(2).unwrap();
+    full.try_push(1u8).unwrap();
+    full.try_push(2).unwrap();
+    assert_eq!(Err(TryReserveError::CapacityOverflow), full.try_push(3));
+    assert_eq!(&[1, 2], &full[..]);
+}
@@ -0,0 +1,97 @@
+use crate::{MyVec, RawVec, TryReserveError};
+
+/// Types for which the all-zero bit pattern is a valid value.
+///
+/// # Safety
+///
+/// Implementors must accept a value whose every byte is zero.
+pub unsafe trait Zeroable {}
+
+macro_rules! impl_zeroable {
+    ($($t:ty),*) => {
+        $(unsafe impl Zeroable for $t {})*
+    };
+}
+
+impl_zeroable!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_zeroable!(f32, f64, bool, char, ());
+
+unsafe impl<T: Zeroable, const N: usize> Zeroable for [T; N] {}
+
+impl<T: Zeroable> MyVec<T> {
+    /// Creates a vector of `n` zero values, straight from zeroed memory.
+    #[cfg(not(no_global_oom_handling))]
+    pub fn zeroed(n: usize) -> Self {
+        Self::try_zeroed(n).unwrap_or_else(|err| err.handle())
+    }
+
+    pub fn try_zeroed(n: usize) -> Result<Self, TryReserveError> {
+        let mut v = MyVec {
+            buf: RawVec::try_with_capacity_zeroed(n)?,
+            len: n,
+        };
+        v.record_len();
+
+        Ok(v)
+    }
+
+    /// Resizes the vector to `new_len`, filling new slots with zeros.
+    ///
+    /// An unallocated vector gets its buffer from zeroed memory; otherwise
+    /// the new slots are cleared with a single `memset`.
+    #[cfg(not(no_global_oom_handling))]
+    pub fn resize_zeroed(&mut self, new_len: usize) {
+        if new_len <= self.len {
+            self.truncate(new_len);
+        } else if self.cap() == 0 {
+            self.buf
+                .try_allocate_zeroed_in_place(new_len)
+                .unwrap_or_else(|err| err.handle());
+
+            self.len = new_len;
+            self.record_len();
+        } else {
+            self.reserve(new_len - self.len);
+
+            unsafe { std::ptr::write_bytes(self.ptr().add(self.len), 0, new_len - self.len) }
+
+            self.len = new_len;
+            self.record_len();
+        }
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn zeroed_construction() {
+    let v = MyVec::<u64>::zeroed(1 << 16);
+    assert_eq!(1 << 16, v.len());
+    assert!(v.iter().all(|&x| x == 0));
+
+    let mut v = MyVec::new();
+    v.resize_zeroed(3);
+    assert_eq!(&[0u8, 0, 0], &v[..]);
+
+    v[1] = 7;
+    v.resize_zeroed(6);
+    assert_eq!(&[0, 7, 0, 0, 0, 0], &v[..]);
+    v.resize_zeroed(2);
+    assert_eq!(&[0, 7], &v[..]);
+
+    let units = MyVec::<()>::zeroed(5);
+    assert_eq!(5, units.len());
+
+    let arrays = MyVec::<[f32; 4]>::zeroed(2);
+    assert_eq!([0.0; 4], arrays[1]);
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[test]
+fn from_elem_and_resize() {
+    let mut v = MyVec::from_elem(String::from("a"), 3);
+    assert_eq!(3, v.len());
+    v.resize(5, String::from("b"));
+    assert_eq!(vec!["a", "a", "a", "b", "b"], v.iter().collect::<Vec<_>>());
+    v.resize(1, String::new());
+    assert_eq!(vec!["a"], v.iter().collect::<Vec<_>>());
+}